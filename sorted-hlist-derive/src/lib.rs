@@ -0,0 +1,154 @@
+//! Derive macro for the [`OrderKey`] trait of `sorted-hlist`.
+//!
+//! This crate is not meant to be used directly; enable the `derive` feature
+//! of `sorted-hlist` and use the re-exported `sorted_hlist::OrderKey` derive
+//! instead.
+//!
+//! [`OrderKey`]: https://docs.rs/sorted-hlist/latest/sorted_hlist/trait.OrderKey.html
+
+use proc_macro::{Delimiter, TokenStream, TokenTree};
+
+/// Derive `sorted_hlist::OrderKey` for a marker type.
+///
+/// The key is given as a non-negative integer literal in an
+/// `#[order_key(N)]` attribute and expands to the corresponding `typenum`
+/// unsigned constant.
+///
+/// Every derive also defines a hidden item named after its key, so two
+/// derives in the same module that accidentally share a key fail to compile
+/// with a "defined multiple times" error.
+///
+/// # Examples
+///
+/// ```rust
+/// use sorted_hlist::prelude::*;
+/// use sorted_hlist::OrderKey;
+///
+/// #[derive(OrderKey)]
+/// #[order_key(3)]
+/// struct CapRead;
+///
+/// #[derive(OrderKey)]
+/// #[order_key(7)]
+/// struct CapWrite;
+///
+/// fn sorted<L: SortedHList>() {}
+/// sorted::<mk_hlist!(CapRead, CapWrite)>();
+/// ```
+///
+/// The attribute is mandatory:
+///
+/// ```rust,compile_fail
+/// use sorted_hlist::OrderKey;
+///
+/// #[derive(OrderKey)]
+/// struct CapRead;
+/// ```
+///
+/// And keys must be unique within a module:
+///
+/// ```rust,compile_fail
+/// use sorted_hlist::OrderKey;
+///
+/// #[derive(OrderKey)]
+/// #[order_key(3)]
+/// struct CapRead;
+///
+/// #[derive(OrderKey)]
+/// #[order_key(3)]
+/// struct CapWrite;
+/// ```
+#[proc_macro_derive(OrderKey, attributes(order_key))]
+pub fn derive_order_key(input: TokenStream) -> TokenStream {
+    match expand(input) {
+        Ok(output) => output,
+        Err(message) => format!("::core::compile_error!({message:?});")
+            .parse()
+            .unwrap(),
+    }
+}
+
+fn expand(input: TokenStream) -> Result<TokenStream, String> {
+    let mut key = None;
+    let mut name = None;
+    let mut tokens = input.into_iter().peekable();
+
+    while let Some(token) = tokens.next() {
+        match token {
+            TokenTree::Punct(punct) if punct.as_char() == '#' => {
+                if let Some(TokenTree::Group(group)) = tokens.next() {
+                    if let Some(value) = parse_order_key(group.stream())? {
+                        if key.replace(value).is_some() {
+                            return Err("duplicate `#[order_key(..)]` attribute".into());
+                        }
+                    }
+                }
+            }
+            TokenTree::Ident(ident)
+                if matches!(ident.to_string().as_str(), "struct" | "enum" | "union") =>
+            {
+                match tokens.next() {
+                    Some(TokenTree::Ident(ident)) => name = Some(ident.to_string()),
+                    _ => return Err("expected a type name".into()),
+                }
+                if let Some(TokenTree::Punct(punct)) = tokens.peek() {
+                    if punct.as_char() == '<' {
+                        return Err("`OrderKey` cannot be derived for generic types".into());
+                    }
+                }
+                break;
+            }
+            _ => {}
+        }
+    }
+
+    let name = name.ok_or("`OrderKey` can only be derived for structs, enums and unions")?;
+    let key = key.ok_or("missing `#[order_key(N)]` attribute")?;
+
+    Ok(format!(
+        "impl ::sorted_hlist::OrderKey for {name} {{ type Key = {ty}; }} \
+         #[doc(hidden)] #[allow(dead_code, non_upper_case_globals)] \
+         const __sorted_hlist_order_key_{key}_is_used_twice_in_this_module: () = ();",
+        ty = unsigned(key),
+    )
+    .parse()
+    .unwrap())
+}
+
+/// Parse the contents of an attribute, returning the key if it is an
+/// `order_key(N)` attribute and `None` for any other attribute.
+fn parse_order_key(attr: TokenStream) -> Result<Option<u128>, String> {
+    let mut tokens = attr.into_iter();
+    match tokens.next() {
+        Some(TokenTree::Ident(ident)) if ident.to_string() == "order_key" => {}
+        _ => return Ok(None),
+    }
+    let args = match tokens.next() {
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis => group,
+        _ => return Err("expected `#[order_key(N)]`".into()),
+    };
+    let mut args = args.stream().into_iter();
+    let literal = match (args.next(), args.next()) {
+        (Some(TokenTree::Literal(literal)), None) => literal.to_string(),
+        _ => return Err("expected a single integer literal in `#[order_key(N)]`".into()),
+    };
+    literal
+        .replace('_', "")
+        .parse()
+        .map(Some)
+        .map_err(|_| format!("`{literal}` is not a non-negative integer literal"))
+}
+
+/// Spell out `n` as a `typenum` unsigned integer type.
+fn unsigned(n: u128) -> String {
+    const TYPENUM: &str = "::sorted_hlist::__private::typenum";
+    if n == 0 {
+        format!("{TYPENUM}::UTerm")
+    } else {
+        format!(
+            "{TYPENUM}::UInt<{}, {TYPENUM}::B{}>",
+            unsigned(n >> 1),
+            n & 1
+        )
+    }
+}
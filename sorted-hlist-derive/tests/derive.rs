@@ -0,0 +1,57 @@
+use sorted_hlist::prelude::*;
+use sorted_hlist::{Key, OrderKey};
+use typenum::{U0, U1000, U3, U7, U9};
+
+#[derive(OrderKey)]
+#[order_key(0)]
+struct CapNone;
+
+#[derive(OrderKey)]
+#[order_key(3)]
+struct CapRead;
+
+#[derive(OrderKey)]
+#[order_key(7)]
+struct CapWrite;
+
+#[derive(OrderKey)]
+#[order_key(9)]
+enum CapExec {}
+
+#[derive(OrderKey)]
+#[order_key(1_000)]
+struct CapAdmin;
+
+const fn sorted<L: SortedHList>() {}
+
+#[test]
+fn derived_keys() {
+    assert_type_eq::<Key<CapNone>, U0>();
+    assert_type_eq::<Key<CapRead>, U3>();
+    assert_type_eq::<Key<CapWrite>, U7>();
+    assert_type_eq::<Key<CapExec>, U9>();
+    assert_type_eq::<Key<CapAdmin>, U1000>();
+}
+
+#[test]
+fn derived_markers_are_sortable() {
+    sorted::<mk_hlist!(CapNone, CapRead, CapWrite, CapExec, CapAdmin)>();
+}
+
+#[test]
+fn derived_markers_intersect() {
+    type A = mk_hlist!(CapRead, CapWrite, CapAdmin);
+    type B = mk_hlist!(CapNone, CapWrite, CapExec, CapAdmin);
+    type Expected = mk_hlist!(CapWrite, CapAdmin);
+    type Computed = <A as Intersect<B>>::Output;
+    assert_type_eq::<Computed, Expected>();
+}
+
+#[test]
+fn derived_markers_mix_with_typenum() {
+    type A = mk_hlist!(U0, CapRead, U7);
+    type B = mk_hlist!(CapNone, U3, CapWrite);
+    type Expected = mk_hlist!(U0, CapRead, U7);
+    type Computed = <A as Intersect<B>>::Output;
+    assert_type_eq::<Computed, Expected>();
+}
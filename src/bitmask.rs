@@ -0,0 +1,153 @@
+//! Sets of small `typenum` integers as bitmasks.
+//!
+//! A list of unsigned integers below 64 converts to a single `typenum`
+//! `Unsigned` with bit `n` set for each element `n`.  Masks can be compared
+//! and combined with typenum's bit operators in one step instead of a walk
+//! over both lists, and [`FromBitmask`] turns a mask back into the sorted
+//! list of its set bits.  [`IntersectViaBitmask`] intersects two such sets
+//! with a single `And` of their masks.
+
+use crate::{HCons, HList, HNil};
+use core::ops::{Add, BitAnd, BitOr, Shl};
+use typenum::{Add1, And, IsLess, Or, Shleft, UInt, UTerm, Unsigned, B0, B1, U0, U1, U64};
+
+/// The number of bits available to a [`ToBitmask`] mask: elements must be
+/// less than this.
+pub type BitmaskWidth = U64;
+
+/// The bitmask of a list of `typenum` unsigned integers below
+/// [`BitmaskWidth`]: the OR of `1 << element` over all elements.
+///
+/// Duplicates and order do not affect the mask.  Elements of 64 or more
+/// fail the `IsLess<BitmaskWidth>` bound.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::ToBitmask;
+/// # use typenum::{Unsigned, U0, U2, U5, U64};
+/// assert_eq!(<mk_hlist!(U0, U2, U5) as ToBitmask>::Output::U64, 0b100101);
+/// ```
+///
+/// ```rust,compile_fail
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::ToBitmask;
+/// # use typenum::{U1, U64};
+/// fn mask<L: ToBitmask>() {}
+/// mask::<mk_hlist!(U1, U64)>();
+/// ```
+pub trait ToBitmask: HList {
+    /// The bitmask of `Self`.
+    type Output: Unsigned;
+}
+
+impl ToBitmask for HNil {
+    type Output = U0;
+}
+
+impl<H, T> ToBitmask for HCons<H, T>
+where
+    // the head's bit ORed into the tail's mask
+    H: Unsigned + IsLess<BitmaskWidth, Output = B1>,
+    T: ToBitmask,
+    U1: Shl<H>,
+    Shleft<U1, H>: BitOr<<T as ToBitmask>::Output>,
+    Or<Shleft<U1, H>, <T as ToBitmask>::Output>: Unsigned,
+{
+    type Output = Or<Shleft<U1, H>, <T as ToBitmask>::Output>;
+}
+
+/// The sorted, duplicate-free list of the set bit positions of a `typenum`
+/// unsigned mask below `2^64`.  The inverse of [`ToBitmask`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::FromBitmask;
+/// # use typenum::{U0, U2, U37, U5};
+/// assert_type_eq::<<U37 as FromBitmask>::Output, mk_hlist!(U0, U2, U5)>();
+/// ```
+pub trait FromBitmask: Unsigned {
+    /// The positions of the set bits, ascending.
+    type Output: HList;
+}
+
+impl<M: BitsFrom<U0>> FromBitmask for M {
+    type Output = <M as BitsFrom<U0>>::Output;
+}
+
+/// The sorted list of set bit positions of the `typenum` unsigned `N`;
+/// shorthand for [`FromBitmask`]'s output.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::SetBits;
+/// # use typenum::{U0, U1, U11, U3};
+/// assert_type_eq::<SetBits<U11>, mk_hlist!(U0, U1, U3)>();
+/// ```
+pub type SetBits<N> = <N as FromBitmask>::Output;
+
+/// Internal helper for [`FromBitmask`]: the set bit positions of `Self`,
+/// shifted up by `Offset`.
+pub trait BitsFrom<Offset>: Unsigned {
+    /// The positions of the set bits plus `Offset`, ascending.
+    type Output: HList;
+}
+
+impl<Offset> BitsFrom<Offset> for UTerm {
+    type Output = HNil;
+}
+
+impl<U, Offset> BitsFrom<Offset> for UInt<U, B1>
+where
+    // the lowest bit is set: Offset is in the set
+    U: Unsigned + BitsFrom<Add1<Offset>>,
+    Offset: Add<B1> + IsLess<BitmaskWidth, Output = B1>,
+{
+    type Output = HCons<Offset, <U as BitsFrom<Add1<Offset>>>::Output>;
+}
+
+impl<U, Offset> BitsFrom<Offset> for UInt<U, B0>
+where
+    // the lowest bit is clear: move on to the next one
+    U: Unsigned + BitsFrom<Add1<Offset>>,
+    Offset: Add<B1>,
+{
+    type Output = <U as BitsFrom<Add1<Offset>>>::Output;
+}
+
+/// Intersection of two lists of `typenum` unsigned integers below
+/// [`BitmaskWidth`], computed by ANDing their [`ToBitmask`] masks and
+/// decoding the result with [`FromBitmask`].
+///
+/// For sorted, duplicate-free inputs the result is the same as
+/// [`Intersect`](crate::Intersect)'s.  Duplicates collapse, as a mask
+/// records each element once.  Every element must be less than 64.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::IntersectViaBitmask;
+/// # use typenum::{U1, U3, U4, U5};
+/// type L = <mk_hlist!(U1, U3, U5) as IntersectViaBitmask<mk_hlist!(U3, U4, U5)>>::Output;
+/// assert_type_eq::<L, mk_hlist!(U3, U5)>();
+/// ```
+pub trait IntersectViaBitmask<Other: HList>: HList {
+    /// The elements in both lists, ascending.
+    type Output: HList;
+}
+
+impl<A, B> IntersectViaBitmask<B> for A
+where
+    A: ToBitmask,
+    B: ToBitmask,
+    <A as ToBitmask>::Output: BitAnd<<B as ToBitmask>::Output>,
+    And<<A as ToBitmask>::Output, <B as ToBitmask>::Output>: FromBitmask,
+{
+    type Output = <And<<A as ToBitmask>::Output, <B as ToBitmask>::Output> as FromBitmask>::Output;
+}
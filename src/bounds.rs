@@ -0,0 +1,489 @@
+//! Binary-search style range queries on sorted HLists, an intersection
+//! that short-circuits on non-overlapping ranges, and queries on the gaps
+//! between consecutive elements.
+
+use crate::{
+    All, EqualTo, FoldLeft, GeOrEq, HCons, HList, HNil, Intersect, MaxFn, SortedHList, Succ, TNone,
+    TOption, TOptionMap, TSome, TypeCmp, TypeCompare,
+};
+use core::ops::{Add, Sub};
+use typenum::{Add1, Bit, Cmp, Compare, Diff, Equal, Greater, Less, Unsigned, B1, U0, U1};
+
+/// Index of the first element that compares `>= T`, as a `typenum`
+/// `Unsigned`.  The type-level equivalent of `std::lower_bound`.
+///
+/// Like `std::lower_bound`, the result is only meaningful when `Self` is
+/// sorted.  If every element is `< T` the result is the length of the list.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::LowerBound;
+/// # use typenum::{Unsigned, U1, U2, U3, U5};
+/// type L = mk_hlist!(U1, U3, U3, U5);
+/// assert_eq!(<L as LowerBound<U3>>::Output::USIZE, 1);
+/// assert_eq!(<L as LowerBound<U2>>::Output::USIZE, 1);
+/// ```
+pub trait LowerBound<T>: HList {
+    /// Index of the first element `>= T`.
+    type Output: Unsigned;
+}
+
+/// Index of the first element that compares `> T`, as a `typenum`
+/// `Unsigned`.  The type-level equivalent of `std::upper_bound`.
+///
+/// `UpperBound<T> - LowerBound<T>` is the number of occurrences of `T`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::{LowerBound, UpperBound};
+/// # use typenum::{Unsigned, U1, U3, U5};
+/// type L = mk_hlist!(U1, U3, U3, U5);
+/// assert_eq!(<L as UpperBound<U3>>::Output::USIZE, 3);
+/// let count = <L as UpperBound<U3>>::Output::USIZE - <L as LowerBound<U3>>::Output::USIZE;
+/// assert_eq!(count, 2);
+/// ```
+pub trait UpperBound<T>: HList {
+    /// Index of the first element `> T`.
+    type Output: Unsigned;
+}
+
+impl<T> LowerBound<T> for HNil {
+    type Output = U0;
+}
+
+impl<T> UpperBound<T> for HNil {
+    type Output = U0;
+}
+
+/// Internal dispatch for [`LowerBound`] on the ordering of the head against `T`.
+pub trait LowerBoundByOrder<T, Ord>: HList {
+    /// Index of the first element `>= T`.
+    type Output: Unsigned;
+}
+
+impl<H, TL, T> LowerBoundByOrder<T, Less> for HCons<H, TL>
+where
+    // H < T -> the bound lies somewhere in the tail
+    TL: LowerBound<T>,
+    <TL as LowerBound<T>>::Output: Add<B1>,
+    Add1<<TL as LowerBound<T>>::Output>: Unsigned,
+{
+    type Output = Add1<<TL as LowerBound<T>>::Output>;
+}
+
+impl<H, TL: HList, T> LowerBoundByOrder<T, Equal> for HCons<H, TL> {
+    type Output = U0;
+}
+
+impl<H, TL: HList, T> LowerBoundByOrder<T, Greater> for HCons<H, TL> {
+    type Output = U0;
+}
+
+impl<H, TL: HList, T> LowerBound<T> for HCons<H, TL>
+where
+    H: TypeCmp<T>,
+    HCons<H, TL>: LowerBoundByOrder<T, TypeCompare<H, T>>,
+{
+    type Output = <Self as LowerBoundByOrder<T, TypeCompare<H, T>>>::Output;
+}
+
+/// Internal dispatch for [`UpperBound`] on the ordering of the head against `T`.
+pub trait UpperBoundByOrder<T, Ord>: HList {
+    /// Index of the first element `> T`.
+    type Output: Unsigned;
+}
+
+impl<H, TL, T> UpperBoundByOrder<T, Less> for HCons<H, TL>
+where
+    // H < T -> the bound lies somewhere in the tail
+    TL: UpperBound<T>,
+    <TL as UpperBound<T>>::Output: Add<B1>,
+    Add1<<TL as UpperBound<T>>::Output>: Unsigned,
+{
+    type Output = Add1<<TL as UpperBound<T>>::Output>;
+}
+
+impl<H, TL, T> UpperBoundByOrder<T, Equal> for HCons<H, TL>
+where
+    // H == T -> still not strictly greater, keep going
+    TL: UpperBound<T>,
+    <TL as UpperBound<T>>::Output: Add<B1>,
+    Add1<<TL as UpperBound<T>>::Output>: Unsigned,
+{
+    type Output = Add1<<TL as UpperBound<T>>::Output>;
+}
+
+impl<H, TL: HList, T> UpperBoundByOrder<T, Greater> for HCons<H, TL> {
+    type Output = U0;
+}
+
+impl<H, TL: HList, T> UpperBound<T> for HCons<H, TL>
+where
+    H: TypeCmp<T>,
+    HCons<H, TL>: UpperBoundByOrder<T, TypeCompare<H, T>>,
+{
+    type Output = <Self as UpperBoundByOrder<T, TypeCompare<H, T>>>::Output;
+}
+
+/// Index of the first element comparing `Equal` to `X` in a sorted list, as
+/// [`TSome`] of a `typenum` `Unsigned`, or [`TNone`] if `X` is absent.
+///
+/// The search stops at the first element greater than `X`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::{SafeIndexOf, TNone, TSome};
+/// # use typenum::{U1, U2, U4, U5, U6};
+/// type L = mk_hlist!(U2, U4, U6);
+/// assert_type_eq::<<L as SafeIndexOf<U4>>::Output, TSome<U1>>();
+/// assert_type_eq::<<L as SafeIndexOf<U5>>::Output, TNone>();
+/// ```
+pub trait SafeIndexOf<X>: HList {
+    /// The index of `X`, if present.
+    type Output: TOption;
+}
+
+impl<X> SafeIndexOf<X> for HNil {
+    type Output = TNone;
+}
+
+impl<H, T: HList, X> SafeIndexOf<X> for HCons<H, T>
+where
+    // Compare the head against X, then dispatch
+    H: TypeCmp<X>,
+    Self: SafeIndexOfByOrder<X, TypeCompare<H, X>>,
+{
+    type Output = <Self as SafeIndexOfByOrder<X, TypeCompare<H, X>>>::Output;
+}
+
+/// Internal dispatch for [`SafeIndexOf`] on the ordering of the head against
+/// `X`.
+pub trait SafeIndexOfByOrder<X, Ord>: HList {
+    /// The index of `X`, if present.
+    type Output: TOption;
+}
+
+impl<H, T, X> SafeIndexOfByOrder<X, Less> for HCons<H, T>
+where
+    // H < X -> one past the index in the tail
+    T: SafeIndexOf<X>,
+    <T as SafeIndexOf<X>>::Output: TOptionMap<Succ>,
+{
+    type Output = <<T as SafeIndexOf<X>>::Output as TOptionMap<Succ>>::Output;
+}
+
+impl<H, T: HList, X> SafeIndexOfByOrder<X, Equal> for HCons<H, T> {
+    // H == X -> found at the head
+    type Output = TSome<U0>;
+}
+
+impl<H, T: HList, X> SafeIndexOfByOrder<X, Greater> for HCons<H, T> {
+    // H > X -> X cannot come later
+    type Output = TNone;
+}
+
+/// The smallest and largest element of a non-empty sorted HList: its first
+/// and its last element.
+pub trait MinMax: HList {
+    /// The first element.
+    type Min;
+    /// The last element.
+    type Max;
+}
+
+impl<H> MinMax for HCons<H, HNil> {
+    type Min = H;
+    type Max = H;
+}
+
+impl<H, HT, TT> MinMax for HCons<H, HCons<HT, TT>>
+where
+    HCons<HT, TT>: MinMax,
+{
+    type Min = H;
+    type Max = <HCons<HT, TT> as MinMax>::Max;
+}
+
+/// Alias for the smallest element of a sorted list.
+pub type MinOf<L> = <L as MinMax>::Min;
+
+/// Alias for the largest element of a sorted list.
+pub type MaxOf<L> = <L as MinMax>::Max;
+
+/// [`Intersect`] with a shortcut for lists whose ranges do not overlap.
+///
+/// The result is always the same as `Intersect`'s.  When
+/// `MaxOf<Self> < MinOf<Other>` or `MaxOf<Other> < MinOf<Self>` the output
+/// is `HNil` without merging the two lists: finding the extremes walks each
+/// list once but compares only two pairs of elements, instead of one
+/// comparison (and one dispatch) per step of the merge.  Lists whose ranges
+/// overlap fall back to `Intersect`, after paying for the two extra
+/// comparisons.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::IntersectFast;
+/// # use typenum::{U1, U2, U7, U8};
+/// type L = <mk_hlist!(U1, U2) as IntersectFast<mk_hlist!(U7, U8)>>::Output;
+/// // L = mk_hlist!(), decided by comparing U2 with U7
+/// ```
+pub trait IntersectFast<Other: HList>: HList {
+    /// Intersection of the two sorted lists.
+    type Output: HList;
+}
+
+impl<Other: SortedHList> IntersectFast<Other> for HNil {
+    type Output = HNil;
+}
+
+impl<H, T> IntersectFast<HNil> for HCons<H, T>
+where
+    HCons<H, T>: SortedHList,
+{
+    type Output = HNil;
+}
+
+impl<HA, TA, HB, TB> IntersectFast<HCons<HB, TB>> for HCons<HA, TA>
+where
+    HCons<HA, TA>: SortedHList + MinMax,
+    HCons<HB, TB>: SortedHList + MinMax,
+    MaxOf<HCons<HA, TA>>: TypeCmp<MinOf<HCons<HB, TB>>>,
+    MaxOf<HCons<HB, TB>>: TypeCmp<MinOf<HCons<HA, TA>>>,
+    Self: IntersectFastByOrder<
+        HCons<HB, TB>,
+        TypeCompare<MaxOf<HCons<HA, TA>>, MinOf<HCons<HB, TB>>>,
+        TypeCompare<MaxOf<HCons<HB, TB>>, MinOf<HCons<HA, TA>>>,
+    >,
+{
+    type Output = <Self as IntersectFastByOrder<
+        HCons<HB, TB>,
+        TypeCompare<MaxOf<HCons<HA, TA>>, MinOf<HCons<HB, TB>>>,
+        TypeCompare<MaxOf<HCons<HB, TB>>, MinOf<HCons<HA, TA>>>,
+    >>::Output;
+}
+
+/// Internal dispatch for [`IntersectFast`] on the orderings of
+/// `MaxOf<Self>` against `MinOf<Rhs>` and of `MaxOf<Rhs>` against
+/// `MinOf<Self>`.
+pub trait IntersectFastByOrder<Rhs: HList, AvsB, BvsA>: HList {
+    /// Intersection of the two sorted lists.
+    type Output: HList;
+}
+
+impl<L: HList, Rhs: HList, BvsA> IntersectFastByOrder<Rhs, Less, BvsA> for L {
+    // max(Self) < min(Rhs) -> disjoint
+    type Output = HNil;
+}
+
+impl<L: HList, Rhs: HList, AvsB: GeOrEq> IntersectFastByOrder<Rhs, AvsB, Less> for L {
+    // max(Rhs) < min(Self) -> disjoint
+    type Output = HNil;
+}
+
+impl<L, Rhs: HList, AvsB: GeOrEq, BvsA: GeOrEq> IntersectFastByOrder<Rhs, AvsB, BvsA> for L
+where
+    // the ranges overlap -> walk both lists
+    L: Intersect<Rhs>,
+{
+    type Output = <L as Intersect<Rhs>>::Output;
+}
+
+/// The differences between consecutive elements of a sorted list of
+/// `typenum` unsigned integers: `(e1 - e0, e2 - e1, ..., en - e(n-1))`.
+///
+/// Lists of fewer than two elements give `HNil`.  Sortedness guarantees
+/// that no subtraction underflows; a repeated element gives `U0`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::Deltas;
+/// # use typenum::{U0, U1, U2, U4, U6};
+/// type Gaps = <mk_hlist!(U1, U2, U6, U6) as Deltas>::Output;
+/// assert_type_eq::<Gaps, mk_hlist!(U1, U4, U0)>();
+/// ```
+pub trait Deltas: SortedHList {
+    /// The adjacent differences.
+    type Output: HList;
+}
+
+impl<L> Deltas for L
+where
+    // check sortedness once, then recurse without it
+    L: SortedHList + DeltasUnchecked,
+{
+    type Output = <L as DeltasUnchecked>::Output;
+}
+
+/// Unchecked version of [`Deltas`]: the adjacent differences of a list
+/// assumed to be sorted.
+pub trait DeltasUnchecked: HList {
+    /// The adjacent differences.
+    type Output: HList;
+}
+
+impl DeltasUnchecked for HNil {
+    type Output = HNil;
+}
+
+impl<H> DeltasUnchecked for HCons<H, HNil> {
+    type Output = HNil;
+}
+
+impl<H, N, T: HList> DeltasUnchecked for HCons<H, HCons<N, T>>
+where
+    // N - H, followed by the deltas from N on
+    N: Sub<H>,
+    HCons<N, T>: DeltasUnchecked,
+{
+    type Output = HCons<Diff<N, H>, <HCons<N, T> as DeltasUnchecked>::Output>;
+}
+
+/// The largest difference between consecutive elements of a sorted list
+/// of `typenum` unsigned integers: the maximum of its [`Deltas`].
+///
+/// Lists of fewer than two elements have no gaps and give `U0`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::MaxGap;
+/// # use typenum::{Unsigned, U0, U16, U4, U8};
+/// type Pages = mk_hlist!(U0, U4, U8, U16);
+/// const _: () = assert!(<Pages as MaxGap>::Output::USIZE <= 8);
+/// ```
+pub trait MaxGap: SortedHList {
+    /// The largest adjacent difference.
+    type Output: Unsigned;
+}
+
+impl<L> MaxGap for L
+where
+    L: Deltas,
+    <L as Deltas>::Output: FoldLeft<MaxFn, U0>,
+    <<L as Deltas>::Output as FoldLeft<MaxFn, U0>>::Output: Unsigned,
+{
+    type Output = <<L as Deltas>::Output as FoldLeft<MaxFn, U0>>::Output;
+}
+
+/// Whether a sorted list of `typenum` unsigned integers is a run of
+/// consecutive integers: every one of its [`Deltas`] is `U1`.
+///
+/// Lists of fewer than two elements are contiguous.  A repeated element
+/// gives a delta of `U0`, so lists with duplicates are not.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::IsContiguous;
+/// # use typenum::{Bit, U4, U5, U6};
+/// assert!(<mk_hlist!(U4, U5, U6) as IsContiguous>::Output::BOOL);
+/// assert!(!<mk_hlist!(U4, U6) as IsContiguous>::Output::BOOL);
+/// ```
+pub trait IsContiguous: SortedHList {
+    /// `B1` if the elements are consecutive, `B0` otherwise.
+    type Output: Bit;
+}
+
+impl<L> IsContiguous for L
+where
+    L: Deltas,
+    <L as Deltas>::Output: All<EqualTo<U1>>,
+{
+    type Output = <<L as Deltas>::Output as All<EqualTo<U1>>>::Output;
+}
+
+/// Every `typenum` unsigned integer from `Self` up to `End` inclusive, in
+/// ascending order; empty when `Self` is greater than `End`.
+///
+/// See [`RangeList`] for the shorthand.
+pub trait UpTo<End>: Unsigned {
+    /// The list `Self, Self + 1, ..., End`.
+    type Output: HList;
+}
+
+impl<Start, End> UpTo<End> for Start
+where
+    // Compare the bounds, then dispatch
+    Start: Unsigned + Cmp<End>,
+    Start: UpToByOrder<End, Compare<Start, End>>,
+{
+    type Output = <Start as UpToByOrder<End, Compare<Start, End>>>::Output;
+}
+
+/// Internal dispatch for [`UpTo`] on the ordering of the two bounds.
+pub trait UpToByOrder<End, Ord>: Unsigned {
+    /// The list `Self, Self + 1, ..., End`.
+    type Output: HList;
+}
+
+impl<Start, End> UpToByOrder<End, Less> for Start
+where
+    // Start < End -> Start, then the range from Start + 1
+    Start: Unsigned + Add<B1>,
+    Add1<Start>: UpTo<End>,
+{
+    type Output = HCons<Start, <Add1<Start> as UpTo<End>>::Output>;
+}
+
+impl<Start: Unsigned, End> UpToByOrder<End, Equal> for Start {
+    // Start == End -> the last element
+    type Output = HCons<Start, HNil>;
+}
+
+impl<Start: Unsigned, End> UpToByOrder<End, Greater> for Start {
+    // Start > End -> empty
+    type Output = HNil;
+}
+
+/// The list of `typenum` unsigned integers from `Start` to `End` inclusive.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::RangeList;
+/// # use typenum::{U2, U3, U4, U5};
+/// assert_type_eq::<RangeList<U2, U5>, mk_hlist!(U2, U3, U4, U5)>();
+/// assert_type_eq::<RangeList<U5, U2>, mk_hlist!()>();
+/// ```
+pub type RangeList<Start, End> = <Start as UpTo<End>>::Output;
+
+/// Every integer between the first and last element of a non-empty sorted
+/// list of `typenum` unsigned integers, inclusive.
+///
+/// The output is the [`RangeList`] from [`MinOf`] to [`MaxOf`]; filling an
+/// already filled list changes nothing.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::FillGaps;
+/// # use typenum::{U2, U3, U4, U5, U6};
+/// type Dense = <mk_hlist!(U2, U5, U6) as FillGaps>::Output;
+/// assert_type_eq::<Dense, mk_hlist!(U2, U3, U4, U5, U6)>();
+/// ```
+pub trait FillGaps: SortedHList + MinMax {
+    /// The contiguous list from the first to the last element.
+    type Output: HList;
+}
+
+impl<L> FillGaps for L
+where
+    L: SortedHList + MinMax,
+    MinOf<L>: UpTo<MaxOf<L>>,
+{
+    type Output = RangeList<MinOf<L>, MaxOf<L>>;
+}
@@ -0,0 +1,84 @@
+//! Trait bounds as types, for asserting bounds on every element of a list.
+//!
+//! Rust has no way to pass a trait as a type parameter, so each bound is
+//! represented by a marker type implementing [`Constraint`] for the types
+//! satisfying it.  Markers for common `core` traits are provided; other
+//! bounds can be added the same way:
+//!
+//! ```rust
+//! # use sorted_hlist::Constraint;
+//! trait Register {}
+//!
+//! struct IsRegister;
+//! impl<T: Register> Constraint<T> for IsRegister {}
+//! ```
+
+use crate::{HCons, HList, HNil};
+
+/// Implemented by a constraint marker for every type `T` satisfying the bound
+/// it stands for.
+pub trait Constraint<T> {}
+
+/// Constraint marker for `core::fmt::Debug`.
+pub struct IsDebug;
+/// Constraint marker for `Send`.
+pub struct IsSend;
+/// Constraint marker for `Sync`.
+pub struct IsSync;
+/// Constraint marker for `Clone`.
+pub struct IsClone;
+/// Constraint marker for `Copy`.
+pub struct IsCopy;
+/// Constraint marker for `Default`.
+pub struct IsDefault;
+
+impl<T: core::fmt::Debug> Constraint<T> for IsDebug {}
+impl<T: Send> Constraint<T> for IsSend {}
+impl<T: Sync> Constraint<T> for IsSync {}
+impl<T: Clone> Constraint<T> for IsClone {}
+impl<T: Copy> Constraint<T> for IsCopy {}
+impl<T: Default> Constraint<T> for IsDefault {}
+
+/// Marker trait for lists whose every element satisfies the constraint `C`.
+pub trait AllSatisfy<C>: HList {}
+
+impl<C> AllSatisfy<C> for HNil {}
+impl<C, H, T> AllSatisfy<C> for HCons<H, T>
+where
+    C: Constraint<H>,
+    T: AllSatisfy<C>,
+{
+}
+
+/// Marker trait for lists whose every element satisfies every constraint in
+/// the HList `Constraints`.
+///
+/// This collapses a bound repeated for each element into a single one.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::{mk_hlist, IsDebug, IsSend, WhereAll};
+/// # use typenum::{U1, U2};
+/// fn log_all<L: WhereAll<mk_hlist!(IsDebug, IsSend)>>() {}
+///
+/// log_all::<mk_hlist!(U1, U2, u8)>();
+/// ```
+///
+/// ```rust,compile_fail
+/// # use sorted_hlist::{mk_hlist, IsDebug, IsSend, WhereAll};
+/// # use typenum::U1;
+/// fn log_all<L: WhereAll<mk_hlist!(IsDebug, IsSend)>>() {}
+///
+/// // `*const u8` is not `Send`
+/// log_all::<mk_hlist!(U1, *const u8)>();
+/// ```
+pub trait WhereAll<Constraints: HList>: HList {}
+
+impl<L: HList> WhereAll<HNil> for L {}
+impl<C, Rest, L> WhereAll<HCons<C, Rest>> for L
+where
+    Rest: HList,
+    L: AllSatisfy<C> + WhereAll<Rest>,
+{
+}
@@ -0,0 +1,117 @@
+//! Lists sorted in descending (non-increasing) order.
+//!
+//! These mirror [`SortedHList`](crate::SortedHList) and
+//! [`Intersect`](crate::Intersect) with the comparisons flipped, so data that
+//! arrives largest-first does not have to be reversed first.
+
+use crate::{HCons, HList, HNil, TypeCmp, TypeCompare};
+use typenum::{Equal, Greater, Less};
+
+/// Marker trait for lists whose element types are in non-increasing order.
+///
+/// Each head `H` must compare geq the next element `HT` via
+/// [`TypeCmp`].
+pub trait ReverseSortedHList: HList {}
+
+impl ReverseSortedHList for HNil {}
+impl<H> ReverseSortedHList for HCons<H, HNil> {}
+impl<H, HT, TT> ReverseSortedHList for HCons<H, HCons<HT, TT>>
+where
+    // tail is already sorted...
+    HCons<HT, TT>: ReverseSortedHList,
+    // and head geq next element
+    H: TypeCmp<HT>,
+    TypeCompare<H, HT>: GeOrEq,
+{
+}
+
+/// Internal helper trait indicating a type-level "geq" relationship for
+/// [`TypeCmp`].
+pub trait GeOrEq {}
+impl GeOrEq for Equal {}
+impl GeOrEq for Greater {}
+
+/// Intersection of two arbitrary HLists using the descending-order walk, with
+/// no sortedness requirements.  Yields an `HList` of the common elements (in
+/// the order of the left list).
+pub trait IntersectDescUnchecked<Other: HList>: HList {
+    /// The resulting list of elements present in both `Self` and `Other`.
+    type Output: HList;
+}
+
+impl<H, T: HList> IntersectDescUnchecked<HNil> for HCons<H, T> {
+    type Output = HNil;
+}
+
+impl<List: HList> IntersectDescUnchecked<List> for HNil {
+    type Output = HNil;
+}
+
+impl<HA, TA: HList, HB, TB: HList> IntersectDescUnchecked<HCons<HB, TB>> for HCons<HA, TA>
+where
+    // Compare the two heads at compile time, then dispatch
+    HA: TypeCmp<HB>,
+    HCons<HA, TA>: IntersectDescByOrder<HCons<HB, TB>, TypeCompare<HA, HB>>,
+{
+    type Output = <Self as IntersectDescByOrder<HCons<HB, TB>, TypeCompare<HA, HB>>>::Output;
+}
+
+/// Internal dispatch for [`IntersectDescUnchecked`] by comparing the heads of
+/// two lists.
+pub trait IntersectDescByOrder<Rhs: HList, Ord>: HList {
+    /// The resulting intersected list after ordering dispatch.
+    type Output: HList;
+}
+
+impl<HA, TA: HList, HB, TB: HList> IntersectDescByOrder<HCons<HB, TB>, Greater> for HCons<HA, TA>
+where
+    // HA > HB -> HA cannot appear further down RHS, drop it
+    TA: IntersectDescUnchecked<HCons<HB, TB>>,
+{
+    type Output = <TA as IntersectDescUnchecked<HCons<HB, TB>>>::Output;
+}
+
+impl<HA, TA: HList, HB, TB: HList> IntersectDescByOrder<HCons<HB, TB>, Less> for HCons<HA, TA>
+where
+    // HA < HB -> drop HB, intersect (HA::TA) and TB
+    HCons<HA, TA>: IntersectDescUnchecked<TB>,
+{
+    type Output = <HCons<HA, TA> as IntersectDescUnchecked<TB>>::Output;
+}
+
+impl<HA, TA: HList, HB, TB: HList> IntersectDescByOrder<HCons<HB, TB>, Equal> for HCons<HA, TA>
+where
+    // HA == HB -> keep HA, then intersect TA and TB
+    TA: IntersectDescUnchecked<TB>,
+{
+    type Output = HCons<HA, <TA as IntersectDescUnchecked<TB>>::Output>;
+}
+
+/// **Checked** intersection of two *descending* HLists, producing a
+/// descending output.
+///
+/// Intersecting the reversals of two sorted lists gives the reversal of their
+/// ascending [`Intersect`](crate::Intersect)ion.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::IntersectDesc;
+/// # use typenum::{U1, U2, U3, U4};
+/// type Common = <mk_hlist!(U4, U3, U2) as IntersectDesc<mk_hlist!(U3, U2, U1)>>::Output;
+/// // Common = mk_hlist!(U3, U2)
+/// ```
+pub trait IntersectDesc<Other: HList>: HList {
+    /// Intersection of two descending lists.
+    type Output: HList;
+}
+
+impl<LA, LB> IntersectDesc<LB> for LA
+where
+    // Only descending lists may use this impl
+    LA: ReverseSortedHList + IntersectDescUnchecked<LB>,
+    LB: ReverseSortedHList,
+{
+    type Output = <LA as IntersectDescUnchecked<LB>>::Output;
+}
@@ -0,0 +1,293 @@
+//! Type-level functions and the folds that apply them across an HList.
+
+use crate::{Concat, HCons, HList, HNil};
+use core::ops::{Add, BitOr, Mul};
+use typenum::{Add1, Max, Maximum, Or, Prod, Sum, Unsigned, B1, U0, U31};
+
+/// A type-level function of one argument, `F(T)`.
+///
+/// Implement it on a marker type for every argument it accepts.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::TypeFn;
+/// # use typenum::{Unsigned, U3};
+/// struct Double;
+///
+/// impl<T: core::ops::Add<T>> TypeFn<T> for Double {
+///     type Output = typenum::Sum<T, T>;
+/// }
+///
+/// assert_eq!(<<Double as TypeFn<U3>>::Output as Unsigned>::USIZE, 6);
+/// ```
+pub trait TypeFn<T> {
+    /// The result of applying the function to `T`.
+    type Output;
+}
+
+/// The [`TypeFn`] adding one to a `typenum` unsigned integer.
+pub struct Succ;
+
+impl<N: Add<B1>> TypeFn<N> for Succ {
+    type Output = Add1<N>;
+}
+
+/// The [`TypeFn`] returning a two-element list of its argument, for use
+/// with [`FlatMap`].
+pub struct Duplicate;
+
+impl<T> TypeFn<T> for Duplicate {
+    type Output = HCons<T, HCons<T, HNil>>;
+}
+
+/// Apply `F`, which returns an HList for each element, and concatenate the
+/// results in order.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::{Duplicate, FlatMap};
+/// # use typenum::{U1, U2};
+/// type L = <mk_hlist!(U1, U2) as FlatMap<Duplicate>>::Output;
+/// assert_type_eq::<L, mk_hlist!(U1, U1, U2, U2)>();
+/// ```
+pub trait FlatMap<F>: HList {
+    /// The concatenated results.
+    type Output: HList;
+}
+
+impl<F> FlatMap<F> for HNil {
+    type Output = HNil;
+}
+
+impl<F, H, T> FlatMap<F> for HCons<H, T>
+where
+    // the head's list followed by the flat-mapped tail
+    F: TypeFn<H>,
+    T: FlatMap<F>,
+    <F as TypeFn<H>>::Output: Concat<<T as FlatMap<F>>::Output>,
+{
+    type Output = <<F as TypeFn<H>>::Output as Concat<<T as FlatMap<F>>::Output>>::Output;
+}
+
+/// A type-level function of two arguments, `F(A, B)`.
+///
+/// Implement it on a marker type for every argument pair it accepts.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::TypeBinaryFn;
+/// # use typenum::{Sum, Unsigned, U1, U2, U3};
+/// struct Add;
+///
+/// impl<A: core::ops::Add<B>, B> TypeBinaryFn<A, B> for Add {
+///     type Output = Sum<A, B>;
+/// }
+///
+/// assert_eq!(<<Add as TypeBinaryFn<U1, U2>>::Output as Unsigned>::USIZE, 3);
+/// ```
+pub trait TypeBinaryFn<A, B> {
+    /// The result of applying the function to `A` and `B`.
+    type Output;
+}
+
+/// The [`TypeBinaryFn`] adding two `typenum` integers.
+///
+/// With [`TypeScan`], `TypeScan<AddFn, U0>` gives inclusive prefix sums;
+/// see [`PrefixSums`] for the exclusive variant.
+pub struct AddFn;
+
+impl<A: Add<B>, B> TypeBinaryFn<A, B> for AddFn {
+    type Output = Sum<A, B>;
+}
+
+/// The [`TypeBinaryFn`] taking the larger of two `typenum` integers.
+///
+/// With [`TypeScan`], `TypeScan<MaxFn, U0>` gives running maxima.
+pub struct MaxFn;
+
+impl<A: Max<B>, B> TypeBinaryFn<A, B> for MaxFn {
+    type Output = Maximum<A, B>;
+}
+
+/// The [`TypeBinaryFn`] multiplying two `typenum` integers.
+pub struct MulFn;
+
+impl<A: Mul<B>, B> TypeBinaryFn<A, B> for MulFn {
+    type Output = Prod<A, B>;
+}
+
+/// The [`TypeBinaryFn`] taking the bitwise OR of two `typenum` unsigned
+/// integers.
+pub struct OrFn;
+
+impl<A: BitOr<B>, B> TypeBinaryFn<A, B> for OrFn {
+    type Output = Or<A, B>;
+}
+
+/// A left fold of a list with `F`, starting from `Init`: the final
+/// accumulator `F(...F(F(Init, A), B)..., Z)`.
+///
+/// The empty list folds to `Init`.  Custom reductions only need a
+/// [`TypeBinaryFn`] impl.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::{AddFn, FoldLeft, MulFn};
+/// # use typenum::{Unsigned, U0, U1, U2, U3, U4};
+/// assert_eq!(<mk_hlist!(U2, U3, U4) as FoldLeft<AddFn, U0>>::Output::USIZE, 9);
+/// assert_eq!(<mk_hlist!(U2, U3, U4) as FoldLeft<MulFn, U1>>::Output::USIZE, 24);
+/// ```
+pub trait FoldLeft<F, Init>: HList {
+    /// The final accumulator.
+    type Output;
+}
+
+impl<F, Init> FoldLeft<F, Init> for HNil {
+    type Output = Init;
+}
+
+impl<F, Init, H, T> FoldLeft<F, Init> for HCons<H, T>
+where
+    // fold the head into the accumulator, then fold the tail from there
+    F: TypeBinaryFn<Init, H>,
+    T: FoldLeft<F, <F as TypeBinaryFn<Init, H>>::Output>,
+{
+    type Output = <T as FoldLeft<F, <F as TypeBinaryFn<Init, H>>::Output>>::Output;
+}
+
+/// The running accumulator states of a left fold with `F`, starting from
+/// `Init`.
+///
+/// For `mk_hlist!(A, B)` the output is `mk_hlist!(F(Init, A), F(F(Init, A), B))`:
+/// one state per element, excluding `Init` itself.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::{AddFn, TypeScan};
+/// # use typenum::{U0, U1, U2, U3, U6};
+/// type Prefix = <mk_hlist!(U1, U2, U3) as TypeScan<AddFn, U0>>::Output;
+/// assert_type_eq::<Prefix, mk_hlist!(U1, U3, U6)>();
+/// ```
+pub trait TypeScan<F, Init>: HList {
+    /// The accumulator after each element.
+    type Output: HList;
+}
+
+impl<F, Init> TypeScan<F, Init> for HNil {
+    type Output = HNil;
+}
+
+impl<F, Init, H, T> TypeScan<F, Init> for HCons<H, T>
+where
+    // the next state, followed by the states of the tail starting from it
+    F: TypeBinaryFn<Init, H>,
+    T: TypeScan<F, <F as TypeBinaryFn<Init, H>>::Output>,
+{
+    type Output = HCons<
+        <F as TypeBinaryFn<Init, H>>::Output,
+        <T as TypeScan<F, <F as TypeBinaryFn<Init, H>>::Output>>::Output,
+    >;
+}
+
+/// The exclusive prefix sums of a list of `typenum` unsigned integers:
+/// element `i` of the output is the sum of inputs `0..i`.
+///
+/// The output has the same length as the input and starts with `U0`, so for
+/// a list of item sizes it gives each item's offset.  Sums of non-negative
+/// values never decrease, so the output is always sorted.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::PrefixSums;
+/// # use typenum::{U0, U2, U4, U6, U8};
+/// type Offsets = <mk_hlist!(U4, U2, U8) as PrefixSums>::Output;
+/// assert_type_eq::<Offsets, mk_hlist!(U0, U4, U6)>();
+/// ```
+pub trait PrefixSums: HList {
+    /// The sum of the elements before each element.
+    type Output: HList;
+}
+
+impl<L: PrefixSumsFrom<U0>> PrefixSums for L {
+    type Output = <L as PrefixSumsFrom<U0>>::Output;
+}
+
+/// Internal helper for [`PrefixSums`]: the prefix sums of `Self`, offset by
+/// the running total `Acc`.
+pub trait PrefixSumsFrom<Acc>: HList {
+    /// `Acc` plus the sum of the elements before each element.
+    type Output: HList;
+}
+
+impl<Acc> PrefixSumsFrom<Acc> for HNil {
+    type Output = HNil;
+}
+
+impl<Acc, H, T> PrefixSumsFrom<Acc> for HCons<H, T>
+where
+    // the running total, then the tail continued from total + head
+    Acc: Add<H>,
+    T: PrefixSumsFrom<Sum<Acc, H>>,
+{
+    type Output = HCons<Acc, <T as PrefixSumsFrom<Sum<Acc, H>>>::Output>;
+}
+
+/// The base of the polynomial [`Fingerprint`] hash.
+pub type FingerprintBase = U31;
+
+/// A deterministic fingerprint of a list of `typenum` unsigned integers.
+///
+/// The fold `acc * FingerprintBase + elem + 1`, starting from zero, without
+/// any modulus: equal lists always agree, and the `+ 1` keeps zero elements
+/// and list length significant.  As the value grows with every element,
+/// long lists of large numbers make for heavy typenum arithmetic.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::Fingerprint;
+/// # use typenum::{Unsigned, U1, U2};
+/// // (0 * 31 + 1 + 1) * 31 + 2 + 1
+/// assert_eq!(<mk_hlist!(U1, U2) as Fingerprint>::Output::USIZE, 65);
+/// ```
+pub trait Fingerprint: HList {
+    /// The fingerprint of `Self`.
+    type Output: Unsigned;
+}
+
+impl<L: FingerprintFrom<U0>> Fingerprint for L {
+    type Output = <L as FingerprintFrom<U0>>::Output;
+}
+
+/// Internal helper for [`Fingerprint`]: the hash of `Self` continued from
+/// the accumulator `Acc`.
+pub trait FingerprintFrom<Acc>: HList {
+    /// The final accumulator.
+    type Output: Unsigned;
+}
+
+impl<Acc: Unsigned> FingerprintFrom<Acc> for HNil {
+    type Output = Acc;
+}
+
+impl<Acc, H, T> FingerprintFrom<Acc> for HCons<H, T>
+where
+    // fold the head into the accumulator, then continue with the tail
+    Acc: Mul<FingerprintBase>,
+    Prod<Acc, FingerprintBase>: Add<H>,
+    Sum<Prod<Acc, FingerprintBase>, H>: Add<B1>,
+    T: FingerprintFrom<Add1<Sum<Prod<Acc, FingerprintBase>, H>>>,
+{
+    type Output = <T as FingerprintFrom<Add1<Sum<Prod<Acc, FingerprintBase>, H>>>>::Output;
+}
@@ -5,14 +5,171 @@
 //!
 //! You can build an HList via the [`mk_hlist!`] macro, mark lists as
 //! [`SortedHList`] when their element types are in non-decreasing order (via
-//! `typenum::Cmp`), and compute the intersection of two sorted lists using
-//! the [`Intersect`] trait (which under the hood uses
-//! [`IntersectUnchecked`]).
+//! [`TypeCmp`], which by default compares each element's [`OrderKey`]), and
+//! compute the intersection of two sorted lists using the [`Intersect`] trait
+//! (which under the hood uses [`IntersectUnchecked`]).
+//!
+//! Sorted lists additionally support range queries via [`LowerBound`] and
+//! [`UpperBound`].  Structural operations such as [`Concat`] and
+//! [`CartesianProduct`] work on any HList.
+//!
+//! Any list can be put in order with [`Sort`], and converted from and to
+//! tuple types with [`FromTuple`] and [`IntoTuple`].
+//!
+//! Lists in descending order are marked by [`ReverseSortedHList`] and
+//! intersected with [`IntersectDesc`].
+//!
+//! Sorted lists of [`KV`] entries act as type-level maps keyed by the
+//! entries' keys.
+//!
+//! Besides [`Intersect`], sorted lists support [`Union`] and
+//! [`Difference`], also through the tag-carrying [`WithMeta`]; set predicates
+//! such as [`IsDisjoint`] and [`PairwiseDisjoint`] yield a `typenum` `Bit`.
+//!
+//! [`WhereAll`] asserts a set of trait bounds on every element of a list, and
+//! type-level functions ([`TypeBinaryFn`]) can be run across one with
+//! [`TypeScan`].
+//!
+//! Sets of small integers convert to and from `typenum` bitmasks with
+//! [`ToBitmask`] and [`FromBitmask`].
+//!
+//! Elements can be tested with [`TypePred`] predicates, which drive
+//! [`Filter`], [`All`] and [`Any`].
+//!
+//! Sorted list values also support `&`, `|` and `-` as shorthand for
+//! intersection, union and difference.
+//!
+//! [`type_list_name`] prints a list's structure for diagnostics.
+//!
+//! For everyday use, `use sorted_hlist::prelude::*;` brings [`mk_hlist!`],
+//! the list types, the marker traits, [`TypeCmp`], the set operations and
+//! the witnesses into scope; everything else is imported by name.
+//!
+//! # Cargo features
+//!
+//! The crate is `no_std` and everything is available without features.
+//!
+//! | Feature  | Enables |
+//! |----------|---------|
+//! | `derive` | `#[derive(OrderKey)]` |
+//! | `alloc`  | linking the `alloc` crate, for APIs that allocate |
+//! | `std`    | linking `std` (implies `alloc`), for APIs built on `std` types |
+//!
+//! Items gated on `alloc` or `std` say so in their documentation.  The
+//! `alloc` feature currently gates no items of its own; `AsSortedTypeIds`,
+//! with its `TypeId` slices and `BTreeSet<TypeId>` conversion, needs `std`.
+//!
+//! The [`Sorted`] proof token offers the set operations as chainable
+//! methods for expression-level use, and functions like [`intersect`] return
+//! `PhantomData` witnesses of their results.
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+use core::fmt;
+use core::hash::{Hash, Hasher};
 use core::marker::PhantomData;
-use typenum::{Cmp, Equal, Greater, Less};
+use typenum::{Bit, Cmp, Compare, NInt, NonZero, PInt, UInt, UTerm, Unsigned, B0, B1, Z0};
+
+pub use typenum::{Equal, Greater, Less};
+
+mod bitmask;
+mod bounds;
+mod constraint;
+mod desc;
+mod fold;
+mod list;
+mod map;
+mod meta;
+mod name;
+mod ops;
+mod pair;
+mod patch;
+mod pred;
+mod rle;
+mod set;
+mod sort;
+mod sorted;
+mod toption;
+mod tuple;
+#[cfg(feature = "std")]
+mod type_ids;
+mod witness;
+
+pub mod prelude;
+
+#[cfg(feature = "derive")]
+pub use sorted_hlist_derive::OrderKey;
+
+#[doc(hidden)]
+pub mod __private {
+    pub use typenum;
+}
+
+pub use bitmask::{BitmaskWidth, BitsFrom, FromBitmask, IntersectViaBitmask, SetBits, ToBitmask};
+pub use bounds::{
+    Deltas, DeltasUnchecked, FillGaps, IntersectFast, IntersectFastByOrder, IsContiguous,
+    LowerBound, LowerBoundByOrder, MaxGap, MaxOf, MinMax, MinOf, RangeList, SafeIndexOf,
+    SafeIndexOfByOrder, UpTo, UpToByOrder, UpperBound, UpperBoundByOrder,
+};
+pub use constraint::{
+    AllSatisfy, Constraint, IsClone, IsCopy, IsDebug, IsDefault, IsSend, IsSync, WhereAll,
+};
+pub use desc::{
+    GeOrEq, IntersectDesc, IntersectDescByOrder, IntersectDescUnchecked, ReverseSortedHList,
+};
+pub use fold::{
+    AddFn, Duplicate, Fingerprint, FingerprintBase, FingerprintFrom, FlatMap, FoldLeft, MaxFn,
+    MulFn, OrFn, PrefixSums, PrefixSumsFrom, Succ, TypeBinaryFn, TypeFn, TypeScan,
+};
+pub use list::{
+    AdjacentPairs, CartesianProduct, CartesianRow, ChunkBy, Concat, Enumerate, EnumerateFrom,
+    ExactLen, Flatten, HList1, HList10, HList11, HList12, HList2, HList3, HList4, HList5, HList6,
+    HList7, HList8, HList9, HListOf, Homogeneous, Len, MaxLen, MinLen, PowerSet, PrependEach,
+    Repeat, Reverse, ReverseOnto, SafeGet, Skip, SkipUpTo, SplitPrefix, Stride, Take,
+};
+pub use map::{
+    GetValue, GetValueByOrder, InsertEntry, InsertEntryByOrder, JoinEntries, JoinEntriesByOrder,
+    Keys, MergeEntries, MergeEntriesByOrder, TPair, Values, KV,
+};
+pub use meta::{HasList, InnerList, WithMeta};
+pub use name::{type_list_name, ListName, TypeListName, TypeName, TypeRepr};
+pub use pair::{ListPair, SortedHListPair};
+pub use patch::{apply_patch, diff_patch, ApplyPatch, DiffPatch, Patch};
+pub use pred::{
+    All, Any, CountWhere, CountWhereByBit, EqualTo, Filter, FilterByBit, Find, FindByBit,
+    GreaterThan, IsEven, LessThan, OnValue, TypePred,
+};
+pub use rle::{
+    GroupConsecutive, PrependRun, PushGroup, PushGroupByOrder, PushRun, PushRunByOrder,
+    RunLengthDecode, RunLengthEncode,
+};
+pub use set::{
+    AllDistinct, AllDistinctByOrder, Complement, Deduplicate, Difference, DifferenceByOrder,
+    DifferenceUnchecked, DisjointFromAll, DropRun, DropRunByOrder, Duplicates, DuplicatesByOrder,
+    HListEq, HListEqByOrder, IntersectLen, IntersectLenByOrder, IntersectWith,
+    IntersectWithByOrder, IntersectWithUnchecked, IsDisjoint, IsDisjointByOrder, KeepVotes,
+    KeepVotesByBit, Member, MemberByOrder, MergeDistinct, MultisetEq, PairwiseDisjoint, SetEq,
+    SubsetOf, TotalCover, Union, UnionAll, UnionByOrder, UnionUnchecked, VotingIntersect,
+};
+pub use sort::{
+    Insert, InsertByOrder, InsertUnique, InsertUniqueByOrder, MergeSorted, MergeSortedByOrder,
+    MergeSortedUnchecked, Normalize, Remove, RemoveByOrder, ReplaceElement, Sort,
+};
+pub use sorted::{sorted, Sorted};
+pub use toption::{IsNone, IsSome, TNone, TOption, TOptionMap, TSome, UnwrapOr};
+pub use tuple::{FromTuple, IntoTuple};
+#[cfg(feature = "std")]
+pub use type_ids::{AsSortedTypeIds, PushTypeIds};
+pub use witness::{
+    assert_sorted, assert_type_eq, difference, intersect, is_sorted_witness, union, AssertLen,
+    AssertSorted, TypeEq,
+};
 
 /// The empty type-level list.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
 pub struct HNil;
 
 /// A non-empty type-level list, with head of type `H` and tail `T`.
@@ -20,20 +177,255 @@ pub struct HNil;
 /// # Type Parameters
 /// - `H`: the type of the first element.
 /// - `T`: the rest of the list (must itself be an `HList`).
+///
+/// Values of `HCons` are zero-sized tags and can be created with
+/// [`HCons::new`] or `Default`.  The standard trait impls below place no
+/// bounds on `H` or `T`.
 pub struct HCons<H, T>(PhantomData<(H, T)>);
 
+impl<H, T> HCons<H, T> {
+    /// Create the zero-sized value of this list type.
+    #[must_use = "this type-level computation produces an output; ignoring it is likely a mistake"]
+    pub const fn new() -> Self {
+        HCons(PhantomData)
+    }
+}
+
+impl<H, T> Clone for HCons<H, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<H, T> Copy for HCons<H, T> {}
+
+impl<H, T> Default for HCons<H, T> {
+    fn default() -> Self {
+        HCons::new()
+    }
+}
+
+impl<H, T> fmt::Debug for HCons<H, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("HCons<_, _>")
+    }
+}
+
+impl<H, T> PartialEq for HCons<H, T> {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl<H, T> Eq for HCons<H, T> {}
+
+impl<H, T> Hash for HCons<H, T> {
+    fn hash<S: Hasher>(&self, _state: &mut S) {}
+}
+
 /// Marker trait for all HLists.
-pub trait HList {}
+///
+/// This trait is sealed and cannot be implemented outside this crate.  Its
+/// only implementors are [`HNil`], [`HCons`] and [`WithMeta`], which wraps a
+/// sorted list built from the other two and reports its length.
+///
+/// ```rust,compile_fail
+/// struct NotAList;
+/// impl sorted_hlist::HList for NotAList {}
+/// ```
+///
+/// [`LEN`](HList::LEN) gives the number of elements as a plain `usize`,
+/// usable in `const` contexts; [`Len`] gives it as a `typenum` integer.
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// const N: usize = <mk_hlist!(u8, bool, char) as HList>::LEN;
+/// let bytes = [0u8; N];
+/// assert_eq!(bytes.len(), 3);
+/// ```
+pub trait HList: sealed::Sealed {
+    /// The number of elements.
+    const LEN: usize;
+}
+
+impl HList for HNil {
+    const LEN: usize = 0;
+}
+
+impl<H, T: HList> HList for HCons<H, T> {
+    const LEN: usize = 1 + T::LEN;
+}
+
+mod sealed {
+    /// Private supertrait of [`HList`](crate::HList).
+    pub trait Sealed {}
+
+    impl Sealed for crate::HNil {}
+    impl<H, T: crate::HList> Sealed for crate::HCons<H, T> {}
+    impl<Tag, List: crate::SortedHList> Sealed for crate::WithMeta<Tag, List> {}
+}
+
+/// The sort key of a list element.
+///
+/// Every comparison in this crate (sortedness, intersection, range queries)
+/// goes through [`TypeCmp`], which by default applies `typenum::Cmp` to the
+/// elements' keys rather than to the elements themselves.  `typenum` integers and bits are their own key, so
+/// lists of plain `typenum` constants work out of the box; marker types opt
+/// in by naming a `typenum` constant as their key.
+///
+/// With the `derive` feature enabled, `#[derive(OrderKey)]` together with an
+/// `#[order_key(N)]` attribute generates the impl.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::OrderKey;
+/// # use typenum::{U1, U2};
+/// struct CapRead;
+/// struct CapWrite;
+///
+/// impl OrderKey for CapRead {
+///     type Key = U1;
+/// }
+/// impl OrderKey for CapWrite {
+///     type Key = U2;
+/// }
+///
+/// type Caps = <mk_hlist!(CapRead, CapWrite) as Intersect<mk_hlist!(CapWrite)>>::Output;
+/// ```
+pub trait OrderKey {
+    /// The `typenum` value this element is ordered by.
+    type Key;
+}
+
+/// Shorthand for the [`OrderKey::Key`] of `T`.
+pub type Key<T> = <T as OrderKey>::Key;
+
+impl OrderKey for UTerm {
+    type Key = Self;
+}
+impl<U: Unsigned, B: Bit> OrderKey for UInt<U, B> {
+    type Key = Self;
+}
+impl OrderKey for Z0 {
+    type Key = Self;
+}
+impl<U: Unsigned + NonZero> OrderKey for PInt<U> {
+    type Key = Self;
+}
+impl<U: Unsigned + NonZero> OrderKey for NInt<U> {
+    type Key = Self;
+}
+impl OrderKey for B0 {
+    type Key = Self;
+}
+impl OrderKey for B1 {
+    type Key = Self;
+}
+
+/// Type-level comparison of two list elements, yielding one of [`Less`],
+/// [`Equal`] or [`Greater`].
+///
+/// This is the comparator behind every ordering-dependent trait in the crate.
+/// It is implemented for all pairs of [`OrderKey`] types whose keys are
+/// `typenum::Cmp`-comparable, which covers `typenum` integers and keyed
+/// markers.  Element types without a key can provide an ordering of their own
+/// by implementing `TypeCmp` directly.
+///
+/// # Migrating from `typenum::Cmp`
+///
+/// This is a breaking change.  Earlier versions compared elements with
+/// `typenum::Cmp` itself, so a user element type with its own `Cmp` impls
+/// and no `OrderKey` impl is no longer sortable or intersectable.
+///
+/// A second blanket impl over `Cmp` cannot be added next to the one over
+/// [`OrderKey`]: the two overlap for any type implementing both traits, which
+/// coherence rejects, and the `OrderKey` impl is what lets keyed markers
+/// compare.  Declaring the type as its own key restores the previous
+/// ordering, since the blanket impl then compares it with the existing `Cmp`
+/// impls:
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::{Equal, Greater, Less, OrderKey};
+/// struct Early;
+/// struct Late;
+///
+/// # macro_rules! legacy_cmp {
+/// #     ($($a:ty, $b:ty => $ord:ty;)*) => {
+/// #         $(impl typenum::Cmp<$b> for $a {
+/// #             type Output = $ord;
+/// #             fn compare<IM: typenum::private::InternalMarker>(&self, _: &$b) -> $ord {
+/// #                 Default::default()
+/// #             }
+/// #         })*
+/// #     };
+/// # }
+/// // existing `typenum::Cmp` impls
+/// legacy_cmp! {
+///     Early, Early => Equal;
+///     Early, Late => Less;
+///     Late, Early => Greater;
+///     Late, Late => Equal;
+/// }
+///
+/// impl OrderKey for Early {
+///     type Key = Self;
+/// }
+/// impl OrderKey for Late {
+///     type Key = Self;
+/// }
+///
+/// const _: () = assert_sorted::<mk_hlist!(Early, Late)>();
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::{Equal, Greater, Less};
+/// struct Low;
+/// struct High;
+///
+/// impl TypeCmp<Low> for Low {
+///     type Output = Equal;
+/// }
+/// impl TypeCmp<High> for Low {
+///     type Output = Less;
+/// }
+/// impl TypeCmp<Low> for High {
+///     type Output = Greater;
+/// }
+/// impl TypeCmp<High> for High {
+///     type Output = Equal;
+/// }
+///
+/// type Common = <mk_hlist!(Low, High) as Intersect<mk_hlist!(High)>>::Output;
+/// ```
+pub trait TypeCmp<Rhs> {
+    /// The ordering of `Self` relative to `Rhs`.
+    type Output;
+}
 
-impl HList for HNil {}
-impl<H, T: HList> HList for HCons<H, T> {}
+impl<A, B> TypeCmp<B> for A
+where
+    A: OrderKey,
+    B: OrderKey,
+    Key<A>: Cmp<Key<B>>,
+{
+    type Output = Compare<Key<A>, Key<B>>;
+}
+
+/// Shorthand for the [`TypeCmp`] ordering of `A` relative to `B`.
+pub type TypeCompare<A, B> = <A as TypeCmp<B>>::Output;
 
 /// Build a type-level `HList` from a comma-separated list of types.
 ///
 /// # Examples
 ///
 /// ```rust
-/// # use sorted_hlist::mk_hlist;
+/// # use sorted_hlist::prelude::*;
 /// type L = mk_hlist!(u8, bool, char);
 /// // Equivalent to HCons<u8, HCons<bool, HCons<char, HNil>>>
 /// ```
@@ -49,22 +441,57 @@ macro_rules! mk_hlist {
 /// Marker trait for lists whose element types are in non-decreasing order.
 ///
 /// A `SortedHList` must satisfy at compile time that each head `H` compares
-/// leq the next element `HT` via `typenum::Cmp<H, HT>`.
+/// leq the next element `HT` via [`TypeCmp`].
+///
+/// The proof checks two adjacent pairs per step, so its depth is about half
+/// the length of the list: a 64-element list needs 32 nested obligations,
+/// well within the default recursion limit.
+///
+/// Like [`HList`], this trait cannot be implemented outside this crate: a
+/// list is only `SortedHList` when the proof above goes through.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use typenum::{U1, U2, U3};
+/// fn sorted<L: SortedHList>() {}
+/// sorted::<mk_hlist!(U1, U2, U2, U3)>();
+/// ```
+///
+/// Any pair out of order is rejected:
+///
+/// ```rust,compile_fail
+/// # use sorted_hlist::prelude::*;
+/// # use typenum::{U1, U2, U3};
+/// fn sorted<L: SortedHList>() {}
+/// sorted::<mk_hlist!(U1, U2, U3, U2)>();
+/// ```
 pub trait SortedHList: HList {}
 
 impl SortedHList for HNil {}
 impl<H> SortedHList for HCons<H, HNil> {}
-impl<H, HT, TT> SortedHList for HCons<H, HCons<HT, TT>>
+impl<H, HT> SortedHList for HCons<H, HCons<HT, HNil>>
+where
+    // head leq the only other element
+    H: TypeCmp<HT>,
+    TypeCompare<H, HT>: LeOrEq,
+{
+}
+impl<H, HT, HTT, TT> SortedHList for HCons<H, HCons<HT, HCons<HTT, TT>>>
 where
-    // tail is already sorted...
-    HCons<HT, TT>: SortedHList,
-    // and head leq next element
-    H: Cmp<HT>,
-    <H as Cmp<HT>>::Output: LeOrEq,
+    // the list from the third element on is sorted...
+    HCons<HTT, TT>: SortedHList,
+    // and the first two pairs are in order
+    H: TypeCmp<HT>,
+    TypeCompare<H, HT>: LeOrEq,
+    HT: TypeCmp<HTT>,
+    TypeCompare<HT, HTT>: LeOrEq,
 {
 }
 
-/// Internal helper trait indicating a type-level "leq" relationship for `Cmp`.
+/// Internal helper trait indicating a type-level "leq" relationship for
+/// [`TypeCmp`].
 pub trait LeOrEq {}
 impl LeOrEq for Equal {}
 impl LeOrEq for Less {}
@@ -124,13 +551,39 @@ where
     type Output = HCons<HA, <TA as IntersectUnchecked<TB>>::Output>;
 }
 
-impl<HA, TA: HList, HB, TB: HList, Ordering> IntersectUnchecked<HCons<HB, TB>> for HCons<HA, TA>
+// An empty side has an empty intersection whatever the ordering, so these
+// impls never conflict with the `HCons`-`HCons` branches above.
+impl<L: HList> IntersectByOrder<HNil, Less> for L {
+    type Output = HNil;
+}
+
+impl<L: HList> IntersectByOrder<HNil, Greater> for L {
+    type Output = HNil;
+}
+
+impl<L: HList> IntersectByOrder<HNil, Equal> for L {
+    type Output = HNil;
+}
+
+impl<HB, TB: HList> IntersectByOrder<HCons<HB, TB>, Less> for HNil {
+    type Output = HNil;
+}
+
+impl<HB, TB: HList> IntersectByOrder<HCons<HB, TB>, Greater> for HNil {
+    type Output = HNil;
+}
+
+impl<HB, TB: HList> IntersectByOrder<HCons<HB, TB>, Equal> for HNil {
+    type Output = HNil;
+}
+
+impl<HA, TA: HList, HB, TB: HList> IntersectUnchecked<HCons<HB, TB>> for HCons<HA, TA>
 where
     // Compare the two heads at compile time, then dispatch
-    HA: Cmp<HB, Output = Ordering>,
-    HCons<HA, TA>: IntersectByOrder<HCons<HB, TB>, Ordering>,
+    HA: TypeCmp<HB>,
+    HCons<HA, TA>: IntersectByOrder<HCons<HB, TB>, TypeCompare<HA, HB>>,
 {
-    type Output = <Self as IntersectByOrder<HCons<HB, TB>, Ordering>>::Output;
+    type Output = <Self as IntersectByOrder<HCons<HB, TB>, TypeCompare<HA, HB>>>::Output;
 }
 
 // TODO: In an ideal world, `Intersect` would itself be constrained on
@@ -159,3 +612,103 @@ where
 {
     type Output = <LA as IntersectUnchecked<LB>>::Output;
 }
+
+/// Intersection of three sorted HLists, `Self ∩ B ∩ C`.
+///
+/// A single bound `A: Intersect3<B, C>` stands in for the chain
+/// `A: Intersect<B>, <A as Intersect<B>>::Output: Intersect<C>`, keeping the
+/// intermediate result out of public signatures.
+pub trait Intersect3<B: HList, C: HList>: HList {
+    /// Intersection of all three lists.
+    type Output: HList;
+}
+
+impl<A, B, C> Intersect3<B, C> for A
+where
+    A: Intersect<B>,
+    B: HList,
+    C: HList,
+    <A as Intersect<B>>::Output: Intersect<C>,
+{
+    type Output = <<A as Intersect<B>>::Output as Intersect<C>>::Output;
+}
+
+/// Intersection of four sorted HLists, `Self ∩ B ∩ C ∩ D`.
+///
+/// See [`Intersect3`].
+pub trait Intersect4<B: HList, C: HList, D: HList>: HList {
+    /// Intersection of all four lists.
+    type Output: HList;
+}
+
+impl<A, B, C, D> Intersect4<B, C, D> for A
+where
+    A: Intersect3<B, C>,
+    B: HList,
+    C: HList,
+    D: HList,
+    <A as Intersect3<B, C>>::Output: Intersect<D>,
+{
+    type Output = <<A as Intersect3<B, C>>::Output as Intersect<D>>::Output;
+}
+
+/// Intersection of a sorted HList with itself, which is the list itself.
+///
+/// Generic code that intersects a type parameter with itself can bound on
+/// `L: IntersectSame` instead of `L: Intersect<L>` to skip the
+/// element-by-element walk.  [`Intersect`] cannot detect the identical case
+/// on its own: an `impl Intersect<L> for L` would overlap the general impl
+/// without specialization.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::IntersectSame;
+/// # use typenum::{U1, U2};
+/// fn same<L: IntersectSame + Intersect<L>>() {}
+/// same::<mk_hlist!(U1, U2)>();
+/// ```
+pub trait IntersectSame: SortedHList {
+    /// Always `Self`.
+    type Output: SortedHList;
+}
+
+impl<L: SortedHList> IntersectSame for L {
+    type Output = L;
+}
+
+/// Intersect any number of sorted HList types, left to right.
+///
+/// `intersect_all!(A, B, C)` expands to
+/// `<<A as Intersect<B>>::Output as Intersect<C>>::Output`.  At least two
+/// arguments are required.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::intersect_all;
+/// # use typenum::{U1, U2, U3, U4};
+/// type L = intersect_all!(mk_hlist!(U1, U2, U3), mk_hlist!(U2, U3, U4), mk_hlist!(U3, U4));
+/// // L = mk_hlist!(U3)
+/// ```
+///
+/// ```rust,compile_fail
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::intersect_all;
+/// # use typenum::U1;
+/// type L = intersect_all!(mk_hlist!(U1));
+/// ```
+#[macro_export]
+macro_rules! intersect_all {
+    ($($only:ty)? $(,)?) => {
+        compile_error!("intersect_all! requires at least two arguments")
+    };
+    ($first:ty, $second:ty $(,)?) => {
+        <$first as $crate::Intersect<$second>>::Output
+    };
+    ($first:ty, $second:ty, $($rest:ty),+ $(,)?) => {
+        $crate::intersect_all!(<$first as $crate::Intersect<$second>>::Output, $($rest),+)
+    };
+}
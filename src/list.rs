@@ -0,0 +1,718 @@
+//! Structural operations on HLists that make no assumption about ordering.
+
+use crate::{mk_hlist, HCons, HList, HNil, PrependRun, TNone, TOption, TSome, KV};
+use core::ops::{Add, Sub};
+use typenum::{Add1, Bit, IsGreaterOrEqual, IsLessOrEqual, NonZero, Sub1, UInt, Unsigned, B1, U0};
+
+/// The HList containing `T` exactly `N` times; `N = U0` gives [`HNil`].
+///
+/// All elements are equal, so the list is a
+/// [`SortedHList`](crate::SortedHList) whenever `T` has an order key.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::Repeat;
+/// # use typenum::U3;
+/// assert_type_eq::<Repeat<u8, U3>, mk_hlist!(u8, u8, u8)>();
+/// ```
+pub type Repeat<T, N> = <HNil as PrependRun<T, N>>::Output;
+
+/// Concatenate two HLists: the elements of `Self` followed by those of
+/// `Other`.
+pub trait Concat<Other: HList>: HList {
+    /// `Self` followed by `Other`.
+    type Output: HList;
+}
+
+impl<Other: HList> Concat<Other> for HNil {
+    type Output = Other;
+}
+
+impl<H, T, Other> Concat<Other> for HCons<H, T>
+where
+    T: Concat<Other>,
+    Other: HList,
+{
+    type Output = HCons<H, <T as Concat<Other>>::Output>;
+}
+
+/// The number of elements of an HList, as a `typenum` `Unsigned`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::Len;
+/// # use typenum::Unsigned;
+/// assert_eq!(<mk_hlist!(u8, u16, u32) as Len>::Output::USIZE, 3);
+/// ```
+pub trait Len: HList {
+    /// The length of `Self`.
+    type Output: Unsigned;
+}
+
+impl Len for HNil {
+    type Output = U0;
+}
+
+impl<H, T> Len for HCons<H, T>
+where
+    T: Len,
+    <T as Len>::Output: Add<B1>,
+    Add1<<T as Len>::Output>: Unsigned,
+{
+    type Output = Add1<<T as Len>::Output>;
+}
+
+/// Bound for HLists with at least `N` elements.
+///
+/// ```rust,compile_fail
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::MinLen;
+/// # use typenum::U2;
+/// fn needs_two<L: MinLen<U2>>() {}
+/// needs_two::<mk_hlist!(u8)>();
+/// ```
+pub trait MinLen<N: Unsigned>: Len {}
+
+impl<L: Len, N: Unsigned> MinLen<N> for L where
+    // len >= N
+    <L as Len>::Output: IsGreaterOrEqual<N, Output = B1>
+{
+}
+
+/// Bound for HLists with at most `N` elements.
+///
+/// ```rust,compile_fail
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::MaxLen;
+/// # use typenum::U2;
+/// fn at_most_two<L: MaxLen<U2>>() {}
+/// at_most_two::<mk_hlist!(u8, u8, u8)>();
+/// ```
+pub trait MaxLen<N: Unsigned>: Len {}
+
+impl<L: Len, N: Unsigned> MaxLen<N> for L where
+    // len <= N
+    <L as Len>::Output: IsLessOrEqual<N, Output = B1>
+{
+}
+
+/// Bound for HLists with exactly `N` elements.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::ExactLen;
+/// # use typenum::U2;
+/// fn pair<L: ExactLen<U2>>() {}
+/// pair::<mk_hlist!(u8, u16)>();
+/// ```
+///
+/// ```rust,compile_fail
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::ExactLen;
+/// # use typenum::U2;
+/// fn pair<L: ExactLen<U2>>() {}
+/// pair::<mk_hlist!(u8)>();
+/// ```
+pub trait ExactLen<N: Unsigned>: MinLen<N> + MaxLen<N> {}
+
+impl<L: MinLen<N> + MaxLen<N>, N: Unsigned> ExactLen<N> for L {}
+
+/// Concatenate any number of HList types, left to right.
+///
+/// `concat_hlists!(A, B, C)` expands to
+/// `<<A as Concat<B>>::Output as Concat<C>>::Output`, and a single argument
+/// is returned unchanged.  At least one argument is required.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::concat_hlists;
+/// # use typenum::{U1, U2, U3};
+/// type L = concat_hlists!(mk_hlist!(U1), mk_hlist!(), mk_hlist!(U2, U3));
+/// // L = mk_hlist!(U1, U2, U3)
+/// ```
+///
+/// ```rust,compile_fail
+/// # use sorted_hlist::concat_hlists;
+/// type L = concat_hlists!();
+/// ```
+#[macro_export]
+macro_rules! concat_hlists {
+    () => {
+        compile_error!("concat_hlists! requires at least one argument")
+    };
+    ($only:ty $(,)?) => { $only };
+    ($first:ty, $second:ty $(, $rest:ty)* $(,)?) => {
+        $crate::concat_hlists!(<$first as $crate::Concat<$second>>::Output $(, $rest)*)
+    };
+}
+
+/// The rest of an HList after a given `Prefix`, so that `Prefix` followed by
+/// `Suffix` is `Self`.
+///
+/// Only implemented when `Self` starts with exactly the types of `Prefix`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::SplitPrefix;
+/// type Body = <mk_hlist!(u8, u16, bool) as SplitPrefix<mk_hlist!(u8)>>::Suffix;
+/// // Body = mk_hlist!(u16, bool)
+/// ```
+///
+/// A prefix that does not match fails to compile:
+///
+/// ```rust,compile_fail
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::SplitPrefix;
+/// fn body<L: SplitPrefix<mk_hlist!(u16)>>() {}
+/// body::<mk_hlist!(u8, u16, bool)>();
+/// ```
+pub trait SplitPrefix<Prefix: HList>: HList {
+    /// `Self` without `Prefix`.
+    type Suffix: HList;
+}
+
+impl<L: HList> SplitPrefix<HNil> for L {
+    type Suffix = L;
+}
+
+impl<H, T, PrefixTail> SplitPrefix<HCons<H, PrefixTail>> for HCons<H, T>
+where
+    // the heads match, split the rest of the prefix off the tail
+    PrefixTail: HList,
+    T: SplitPrefix<PrefixTail>,
+{
+    type Suffix = <T as SplitPrefix<PrefixTail>>::Suffix;
+}
+
+/// Flatten an HList of HLists into a single HList, keeping the order of the
+/// elements.  Empty inner lists disappear.
+///
+/// This undoes groupings such as [`ChunkBy`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::Flatten;
+/// # use typenum::{U1, U2, U3};
+/// type L = <mk_hlist!(mk_hlist!(U1, U2), mk_hlist!(), mk_hlist!(U3)) as Flatten>::Output;
+/// // L = mk_hlist!(U1, U2, U3)
+/// ```
+pub trait Flatten: HList {
+    /// The concatenation of all inner lists.
+    type Output: HList;
+}
+
+impl Flatten for HNil {
+    type Output = HNil;
+}
+
+impl<H, T> Flatten for HCons<H, T>
+where
+    // the head list followed by the flattened tail
+    T: Flatten,
+    H: Concat<<T as Flatten>::Output>,
+{
+    type Output = <H as Concat<<T as Flatten>::Output>>::Output;
+}
+
+/// The Cartesian product of two HLists, as an HList of `(A, B)` tuples.
+///
+/// Pairs are produced in row-major order: every element of `Self` is paired
+/// with each element of `Other` in turn.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::CartesianProduct;
+/// # use typenum::{U1, U2};
+/// type P = <mk_hlist!(u8, u16) as CartesianProduct<mk_hlist!(U1, U2)>>::Output;
+/// // P = mk_hlist!((u8, U1), (u8, U2), (u16, U1), (u16, U2))
+/// ```
+pub trait CartesianProduct<Other: HList>: HList {
+    /// The list of all `(A, B)` pairs.
+    type Output: HList;
+}
+
+impl<Other: HList> CartesianProduct<Other> for HNil {
+    type Output = HNil;
+}
+
+impl<H, T, Other> CartesianProduct<Other> for HCons<H, T>
+where
+    // one row per left element, followed by the rest of the product
+    Other: CartesianRow<H>,
+    T: CartesianProduct<Other>,
+    <Other as CartesianRow<H>>::Output: Concat<<T as CartesianProduct<Other>>::Output>,
+{
+    type Output = <<Other as CartesianRow<H>>::Output as Concat<
+        <T as CartesianProduct<Other>>::Output,
+    >>::Output;
+}
+
+/// Internal helper for [`CartesianProduct`]: pairs a fixed left element `A`
+/// with every element of `Self`.
+pub trait CartesianRow<A>: HList {
+    /// The list of `(A, X)` pairs for each `X` in `Self`.
+    type Output: HList;
+}
+
+impl<A> CartesianRow<A> for HNil {
+    type Output = HNil;
+}
+
+impl<A, H, T: CartesianRow<A>> CartesianRow<A> for HCons<H, T> {
+    type Output = HCons<(A, H), <T as CartesianRow<A>>::Output>;
+}
+
+/// All `2^N` subsets of an HList, as an HList of HLists.
+///
+/// Subsets keep the relative order of `Self`.  Those without the head come
+/// first, followed by the same subsets with the head prepended; e.g. the
+/// power set of `(A, B)` is `((), (B), (A), (A, B))`.
+///
+/// The output grows exponentially, so this is only practical for small lists
+/// (roughly `N <= 10`) before the compiler's recursion limit gets in the way.
+pub trait PowerSet: HList {
+    /// The list of all subsets of `Self`.
+    type Output: HList;
+}
+
+impl PowerSet for HNil {
+    type Output = HCons<HNil, HNil>;
+}
+
+impl<H, T> PowerSet for HCons<H, T>
+where
+    // subsets of the tail, without and then with the head
+    T: PowerSet,
+    <T as PowerSet>::Output: PrependEach<H>,
+    <T as PowerSet>::Output: Concat<<<T as PowerSet>::Output as PrependEach<H>>::Output>,
+{
+    type Output = <<T as PowerSet>::Output as Concat<
+        <<T as PowerSet>::Output as PrependEach<H>>::Output,
+    >>::Output;
+}
+
+/// Internal helper for [`PowerSet`]: prepends `A` to every list in an HList
+/// of HLists.
+pub trait PrependEach<A>: HList {
+    /// `Self` with `A` prepended to each element.
+    type Output: HList;
+}
+
+impl<A> PrependEach<A> for HNil {
+    type Output = HNil;
+}
+
+impl<A, H, T: PrependEach<A>> PrependEach<A> for HCons<H, T> {
+    type Output = HCons<HCons<A, H>, <T as PrependEach<A>>::Output>;
+}
+
+/// The first `N` elements of an HList.
+///
+/// Only implemented when the list has at least `N` elements.
+pub trait Take<N: Unsigned>: HList {
+    /// The first `N` elements of `Self`.
+    type Output: HList;
+}
+
+impl<L: HList> Take<U0> for L {
+    type Output = HNil;
+}
+
+impl<H, T, U: Unsigned, B: Bit> Take<UInt<U, B>> for HCons<H, T>
+where
+    // keep the head, take N - 1 from the tail
+    UInt<U, B>: Sub<B1>,
+    Sub1<UInt<U, B>>: Unsigned,
+    T: Take<Sub1<UInt<U, B>>>,
+{
+    type Output = HCons<H, <T as Take<Sub1<UInt<U, B>>>>::Output>;
+}
+
+/// The element at index `N` as [`TSome`], or [`TNone`] if the list has at
+/// most `N` elements.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::{SafeGet, TNone, TSome};
+/// # use typenum::{U1, U2};
+/// assert_type_eq::<<mk_hlist!(u8, u16) as SafeGet<U1>>::Output, TSome<u16>>();
+/// assert_type_eq::<<mk_hlist!(u8, u16) as SafeGet<U2>>::Output, TNone>();
+/// ```
+pub trait SafeGet<N: Unsigned>: HList {
+    /// The element at `N`, if any.
+    type Output: TOption;
+}
+
+impl<N: Unsigned> SafeGet<N> for HNil {
+    type Output = TNone;
+}
+
+impl<H, T: HList> SafeGet<U0> for HCons<H, T> {
+    type Output = TSome<H>;
+}
+
+impl<H, T, U: Unsigned, B: Bit> SafeGet<UInt<U, B>> for HCons<H, T>
+where
+    // look up N - 1 in the tail
+    UInt<U, B>: Sub<B1>,
+    Sub1<UInt<U, B>>: Unsigned,
+    T: SafeGet<Sub1<UInt<U, B>>>,
+{
+    type Output = <T as SafeGet<Sub1<UInt<U, B>>>>::Output;
+}
+
+/// An HList without its first `N` elements.
+///
+/// Only implemented when the list has at least `N` elements.
+pub trait Skip<N: Unsigned>: HList {
+    /// `Self` without its first `N` elements.
+    type Output: HList;
+}
+
+impl<L: HList> Skip<U0> for L {
+    type Output = L;
+}
+
+impl<H, T, U: Unsigned, B: Bit> Skip<UInt<U, B>> for HCons<H, T>
+where
+    // drop the head, skip N - 1 from the tail
+    UInt<U, B>: Sub<B1>,
+    Sub1<UInt<U, B>>: Unsigned,
+    T: Skip<Sub1<UInt<U, B>>>,
+{
+    type Output = <T as Skip<Sub1<UInt<U, B>>>>::Output;
+}
+
+/// An HList without its first `N` elements, or [`HNil`] when it has fewer
+/// than `N`.
+///
+/// The saturating counterpart of [`Skip`], implemented for every list.
+pub trait SkipUpTo<N: Unsigned>: HList {
+    /// `Self` without its first `N` elements.
+    type Output: HList;
+}
+
+impl<N: Unsigned> SkipUpTo<N> for HNil {
+    type Output = HNil;
+}
+
+impl<H, T: HList> SkipUpTo<U0> for HCons<H, T> {
+    type Output = Self;
+}
+
+impl<H, T, U: Unsigned, B: Bit> SkipUpTo<UInt<U, B>> for HCons<H, T>
+where
+    // drop the head, skip up to N - 1 from the tail
+    UInt<U, B>: Sub<B1>,
+    Sub1<UInt<U, B>>: Unsigned,
+    T: SkipUpTo<Sub1<UInt<U, B>>>,
+{
+    type Output = <T as SkipUpTo<Sub1<UInt<U, B>>>>::Output;
+}
+
+/// Every `N`-th element of an HList, starting with the first.
+///
+/// After each kept element the next `N - 1` elements are dropped with
+/// [`SkipUpTo`], so any length works; a shorter final stretch just ends the
+/// list.  `N` must be non-zero.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::Stride;
+/// # use typenum::{U1, U2, U3, U4, U5, U6};
+/// type Odds = <mk_hlist!(U1, U2, U3, U4, U5, U6) as Stride<U2>>::Output;
+/// assert_type_eq::<Odds, mk_hlist!(U1, U3, U5)>();
+/// ```
+pub trait Stride<N: Unsigned>: HList {
+    /// Every `N`-th element of `Self`.
+    type Output: HList;
+}
+
+impl<N: Unsigned> Stride<N> for HNil {
+    type Output = HNil;
+}
+
+impl<H, T, N> Stride<N> for HCons<H, T>
+where
+    // keep the head, drop up to the next N - 1 elements and continue
+    N: Unsigned + Sub<B1>,
+    Sub1<N>: Unsigned,
+    T: SkipUpTo<Sub1<N>>,
+    <T as SkipUpTo<Sub1<N>>>::Output: Stride<N>,
+{
+    type Output = HCons<H, <<T as SkipUpTo<Sub1<N>>>::Output as Stride<N>>::Output>;
+}
+
+/// The overlapping windows of two consecutive elements of an HList, as an
+/// HList of `(H_i, H_{i+1})` tuples.  Lists with fewer than two elements
+/// have no pairs.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::AdjacentPairs;
+/// # use typenum::{U1, U2, U3};
+/// type P = <mk_hlist!(U1, U2, U3) as AdjacentPairs>::Output;
+/// // P = mk_hlist!((U1, U2), (U2, U3))
+/// ```
+pub trait AdjacentPairs: HList {
+    /// The consecutive pairs of `Self`.
+    type Output: HList;
+}
+
+impl AdjacentPairs for HNil {
+    type Output = HNil;
+}
+
+impl<H> AdjacentPairs for HCons<H, HNil> {
+    type Output = HNil;
+}
+
+impl<H, N, T> AdjacentPairs for HCons<H, HCons<N, T>>
+where
+    HCons<N, T>: AdjacentPairs,
+{
+    type Output = HCons<(H, N), <HCons<N, T> as AdjacentPairs>::Output>;
+}
+
+/// Split an HList into consecutive chunks of `N` elements each, yielding an
+/// HList of HLists.
+///
+/// The length of `Self` must be a multiple of `N` (and `N` must be non-zero);
+/// otherwise the trait is not implemented.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::ChunkBy;
+/// # use typenum::{U1, U2, U3, U4};
+/// type Pairs = <mk_hlist!(U1, U2, U3, U4) as ChunkBy<U2>>::Output;
+/// // Pairs = mk_hlist!(mk_hlist!(U1, U2), mk_hlist!(U3, U4))
+/// ```
+///
+/// A length that is not a multiple of `N` fails to compile:
+///
+/// ```rust,compile_fail
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::ChunkBy;
+/// # use typenum::{U1, U2, U3};
+/// fn chunks<L: ChunkBy<U2>>() {}
+/// chunks::<mk_hlist!(U1, U2, U3)>();
+/// ```
+pub trait ChunkBy<N: Unsigned + NonZero>: HList {
+    /// The list of chunks.
+    type Output: HList;
+}
+
+impl<N: Unsigned + NonZero> ChunkBy<N> for HNil {
+    type Output = HNil;
+}
+
+impl<N: Unsigned + NonZero, H, T: HList> ChunkBy<N> for HCons<H, T>
+where
+    // the first N elements form a chunk, the rest is chunked recursively
+    HCons<H, T>: Take<N> + Skip<N>,
+    <HCons<H, T> as Skip<N>>::Output: ChunkBy<N>,
+{
+    type Output = HCons<
+        <HCons<H, T> as Take<N>>::Output,
+        <<HCons<H, T> as Skip<N>>::Output as ChunkBy<N>>::Output,
+    >;
+}
+
+/// Marker trait for HLists whose elements are all of the same type.
+///
+/// The empty list is vacuously homogeneous; as it has no element type, its
+/// `Elem` is the uninhabited `core::convert::Infallible`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::Homogeneous;
+/// fn bytes<L: Homogeneous<Elem = u8>>() {}
+/// bytes::<mk_hlist!(u8, u8, u8)>();
+/// ```
+///
+/// ```rust,compile_fail
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::Homogeneous;
+/// fn bytes<L: Homogeneous<Elem = u8>>() {}
+/// bytes::<mk_hlist!(u8, u16)>();
+/// ```
+pub trait Homogeneous: HList {
+    /// The common element type.
+    type Elem;
+}
+
+impl Homogeneous for HNil {
+    type Elem = core::convert::Infallible;
+}
+
+impl<H> Homogeneous for HCons<H, HNil> {
+    type Elem = H;
+}
+
+impl<H, HT, TT> Homogeneous for HCons<H, HCons<HT, TT>>
+where
+    // the tail is homogeneous with the same element type as the head
+    HCons<HT, TT>: Homogeneous<Elem = H>,
+{
+    type Elem = H;
+}
+
+/// Marker trait for HLists whose elements are all `Elem`.
+///
+/// Unlike [`Homogeneous`], the element type is a parameter, so the empty
+/// list is an `HListOf` every type.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::HListOf;
+/// fn bytes<L: HListOf<u8>>() {}
+/// bytes::<mk_hlist!(u8, u8, u8)>();
+/// bytes::<mk_hlist!()>();
+/// ```
+///
+/// ```rust,compile_fail
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::HListOf;
+/// fn bytes<L: HListOf<u8>>() {}
+/// bytes::<mk_hlist!(u8, u16)>();
+/// ```
+pub trait HListOf<Elem>: HList {}
+
+impl<Elem> HListOf<Elem> for HNil {}
+
+impl<Elem, Tail: HListOf<Elem>> HListOf<Elem> for HCons<Elem, Tail> {}
+
+/// Pair each element with its index, as `KV<Index, Element>` entries with
+/// indices counting up from `U0`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::{Enumerate, KV};
+/// # use typenum::{U0, U1};
+/// type L = <mk_hlist!(u8, bool) as Enumerate>::Output;
+/// assert_type_eq::<L, mk_hlist!(KV<U0, u8>, KV<U1, bool>)>();
+/// ```
+///
+/// With [`OnValue`](crate::OnValue), [`Filter`](crate::Filter) and
+/// [`Keys`](crate::Keys) this finds the positions of matching elements:
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::{Enumerate, Filter, IsEven, Keys, OnValue};
+/// # use typenum::{U1, U3, U4, U7, U8};
+/// type Even = <<mk_hlist!(U3, U4, U7, U8) as Enumerate>::Output as Filter<OnValue<IsEven>>>::Output;
+/// assert_type_eq::<<Even as Keys>::Output, mk_hlist!(U1, U3)>();
+/// ```
+pub trait Enumerate: HList {
+    /// The indexed elements.
+    type Output: HList;
+}
+
+impl<L: EnumerateFrom<U0>> Enumerate for L {
+    type Output = <L as EnumerateFrom<U0>>::Output;
+}
+
+/// Internal helper for [`Enumerate`]: pairs each element with its index,
+/// counting from `I`.
+pub trait EnumerateFrom<I>: HList {
+    /// The indexed elements.
+    type Output: HList;
+}
+
+impl<I> EnumerateFrom<I> for HNil {
+    type Output = HNil;
+}
+
+impl<I, H, T> EnumerateFrom<I> for HCons<H, T>
+where
+    // the head at I, the tail from I + 1
+    I: Add<B1>,
+    T: EnumerateFrom<Add1<I>>,
+{
+    type Output = HCons<KV<I, H>, <T as EnumerateFrom<Add1<I>>>::Output>;
+}
+
+/// The elements of an HList in reverse order.
+///
+/// Reversing a [`SortedHList`](crate::SortedHList) yields a
+/// [`ReverseSortedHList`](crate::ReverseSortedHList) and vice versa.
+pub trait Reverse: HList {
+    /// `Self` in reverse order.
+    type Output: HList;
+}
+
+impl<L: ReverseOnto<HNil>> Reverse for L {
+    type Output = <L as ReverseOnto<HNil>>::Output;
+}
+
+/// Internal helper for [`Reverse`]: prepends the elements of `Self` onto
+/// `Acc` one by one, leaving them in reverse order.
+pub trait ReverseOnto<Acc: HList>: HList {
+    /// `Self` reversed, followed by `Acc`.
+    type Output: HList;
+}
+
+impl<Acc: HList> ReverseOnto<Acc> for HNil {
+    type Output = Acc;
+}
+
+impl<H, T, Acc: HList> ReverseOnto<Acc> for HCons<H, T>
+where
+    T: ReverseOnto<HCons<H, Acc>>,
+{
+    type Output = <T as ReverseOnto<HCons<H, Acc>>>::Output;
+}
+
+// Aliases for lists of one to twelve elements, each documented with its
+// expansion.
+macro_rules! hlist_aliases {
+    ($($name:ident<$($param:ident),+> = $expansion:literal;)*) => {
+        $(
+            #[doc = concat!("`", $expansion, "`")]
+            pub type $name<$($param),+> = mk_hlist!($($param),+);
+        )*
+    };
+}
+
+hlist_aliases! {
+    HList1<A> = "HCons<A, HNil>";
+    HList2<A, B> = "HCons<A, HCons<B, HNil>>";
+    HList3<A, B, C> = "HCons<A, HCons<B, HCons<C, HNil>>>";
+    HList4<A, B, C, D> = "HCons<A, HCons<B, HCons<C, HCons<D, HNil>>>>";
+    HList5<A, B, C, D, E> = "HCons<A, HCons<B, HCons<C, HCons<D, HCons<E, HNil>>>>>";
+    HList6<A, B, C, D, E, F> = "HCons<A, HCons<B, HCons<C, HCons<D, HCons<E, HCons<F, HNil>>>>>>";
+    HList7<A, B, C, D, E, F, G> = "HCons<A, HCons<B, HCons<C, HCons<D, HCons<E, HCons<F, HCons<G, HNil>>>>>>>";
+    HList8<A, B, C, D, E, F, G, H> = "HCons<A, HCons<B, HCons<C, HCons<D, HCons<E, HCons<F, HCons<G, HCons<H, HNil>>>>>>>>";
+    HList9<A, B, C, D, E, F, G, H, I> = "HCons<A, HCons<B, HCons<C, HCons<D, HCons<E, HCons<F, HCons<G, HCons<H, HCons<I, HNil>>>>>>>>>";
+    HList10<A, B, C, D, E, F, G, H, I, J> = "HCons<A, HCons<B, HCons<C, HCons<D, HCons<E, HCons<F, HCons<G, HCons<H, HCons<I, HCons<J, HNil>>>>>>>>>>";
+    HList11<A, B, C, D, E, F, G, H, I, J, K> = "HCons<A, HCons<B, HCons<C, HCons<D, HCons<E, HCons<F, HCons<G, HCons<H, HCons<I, HCons<J, HCons<K, HNil>>>>>>>>>>>";
+    HList12<A, B, C, D, E, F, G, H, I, J, K, L> = "HCons<A, HCons<B, HCons<C, HCons<D, HCons<E, HCons<F, HCons<G, HCons<H, HCons<I, HCons<J, HCons<K, HCons<L, HNil>>>>>>>>>>>>";
+}
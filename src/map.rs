@@ -0,0 +1,347 @@
+//! Type-level maps: sorted HLists of key–value entries.
+//!
+//! A map is an HList of [`KV`] entries.  Entries are ordered by their key
+//! alone, so a map whose keys are non-decreasing is a [`SortedHList`] and all
+//! the set operations apply to it directly.  [`Intersect`] matches entries on
+//! their keys and, as with plain lists, keeps the entries of the left
+//! operand.
+//!
+//! [`SortedHList`]: crate::SortedHList
+//! [`Intersect`]: crate::Intersect
+
+use crate::{HCons, HList, HNil, Key, OrderKey, TypeCmp, TypeCompare};
+use core::marker::PhantomData;
+use typenum::{Equal, Greater, Less};
+
+/// A map entry associating the payload type `V` with the key `K`.
+///
+/// The entry is ordered by the [`OrderKey`] of `K`; `V` carries no ordering
+/// requirements.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::KV;
+/// # use typenum::{U0, U4, U8};
+/// struct Ctrl;
+/// struct Status;
+/// struct Data;
+///
+/// type Regs = mk_hlist!(KV<U0, Ctrl>, KV<U4, Status>, KV<U8, Data>);
+/// type Used = <Regs as Intersect<mk_hlist!(U4)>>::Output;
+/// // Used = mk_hlist!(KV<U4, Status>)
+/// ```
+pub struct KV<K, V>(PhantomData<(K, V)>);
+
+impl<K: OrderKey, V> OrderKey for KV<K, V> {
+    type Key = Key<K>;
+}
+
+/// A type-level pair, used e.g. by [`JoinEntries`] to hold the values of both
+/// joined maps.
+pub struct TPair<A, B>(PhantomData<(A, B)>);
+
+/// The keys of a map, in order.
+///
+/// The keys of a sorted map form a [`SortedHList`] themselves, so they can be
+/// intersected with plain sorted lists.
+///
+/// [`SortedHList`]: crate::SortedHList
+pub trait Keys: HList {
+    /// The list of keys.
+    type Output: HList;
+}
+
+impl Keys for HNil {
+    type Output = HNil;
+}
+
+impl<K, V, T: Keys> Keys for HCons<KV<K, V>, T> {
+    type Output = HCons<K, <T as Keys>::Output>;
+}
+
+/// The values of a map, in key order.
+///
+/// Values carry no ordering guarantee.
+pub trait Values: HList {
+    /// The list of values.
+    type Output: HList;
+}
+
+impl Values for HNil {
+    type Output = HNil;
+}
+
+impl<K, V, T: Values> Values for HCons<KV<K, V>, T> {
+    type Output = HCons<V, <T as Values>::Output>;
+}
+
+/// Look up the value stored under key `K` in a sorted map.
+///
+/// `Output` is the `V` of the entry whose key compares `Equal` to `K`.  The
+/// lookup stops at the first entry with a greater key, so a key that is
+/// absent from the map leaves the trait unimplemented.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::{GetValue, KV};
+/// # use typenum::{U0, U4};
+/// type Regs = mk_hlist!(KV<U0, u8>, KV<U4, u32>);
+/// let status: <Regs as GetValue<U4>>::Output = 0u32;
+/// ```
+///
+/// Looking up a missing key fails to compile:
+///
+/// ```rust,compile_fail
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::{GetValue, KV};
+/// # use typenum::{U0, U2, U4};
+/// type Regs = mk_hlist!(KV<U0, u8>, KV<U4, u32>);
+/// type Missing = <Regs as GetValue<U2>>::Output;
+/// let _: Option<Missing> = None;
+/// ```
+pub trait GetValue<K>: HList {
+    /// The value type stored under `K`.
+    type Output;
+}
+
+impl<EK, V, T: HList, K> GetValue<K> for HCons<KV<EK, V>, T>
+where
+    // Compare the entry key against the requested key, then dispatch
+    EK: TypeCmp<K>,
+    Self: GetValueByOrder<K, TypeCompare<EK, K>>,
+{
+    type Output = <Self as GetValueByOrder<K, TypeCompare<EK, K>>>::Output;
+}
+
+/// Internal dispatch for [`GetValue`] on the ordering of the head entry's key
+/// against the requested key.  There is deliberately no `Greater` branch: in
+/// a sorted map the key cannot appear any further down.
+pub trait GetValueByOrder<K, Ord>: HList {
+    /// The value type stored under `K`.
+    type Output;
+}
+
+impl<EK, V, T: HList, K> GetValueByOrder<K, Equal> for HCons<KV<EK, V>, T> {
+    type Output = V;
+}
+
+impl<EK, V, T, K> GetValueByOrder<K, Less> for HCons<KV<EK, V>, T>
+where
+    // entry key < K -> keep looking in the tail
+    T: GetValue<K>,
+{
+    type Output = <T as GetValue<K>>::Output;
+}
+
+/// Insert the entry `KV<K, V>` into a sorted map, or update it.
+///
+/// This has upsert semantics:
+/// - if no entry's key compares `Equal` to `K`, the new entry is inserted at
+///   its key-ordered position;
+/// - otherwise the first such entry is replaced by `KV<K, V>`, dropping its
+///   old value.
+///
+/// Either way the output remains sorted by key.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::{GetValue, InsertEntry, KV};
+/// # use typenum::{U0, U4, U8};
+/// type Regs = mk_hlist!(KV<U0, u8>, KV<U8, u8>);
+/// type Inserted = <Regs as InsertEntry<U4, u16>>::Output;
+/// type Updated = <Inserted as InsertEntry<U8, u32>>::Output;
+/// // Updated = mk_hlist!(KV<U0, u8>, KV<U4, u16>, KV<U8, u32>)
+/// let data: <Updated as GetValue<U8>>::Output = 0u32;
+/// ```
+pub trait InsertEntry<K, V>: HList {
+    /// The map with the entry inserted or updated.
+    type Output: HList;
+}
+
+impl<K, V> InsertEntry<K, V> for HNil {
+    type Output = HCons<KV<K, V>, HNil>;
+}
+
+impl<EK, EV, T: HList, K, V> InsertEntry<K, V> for HCons<KV<EK, EV>, T>
+where
+    // Compare the entry key against the new key, then dispatch
+    EK: TypeCmp<K>,
+    Self: InsertEntryByOrder<K, V, TypeCompare<EK, K>>,
+{
+    type Output = <Self as InsertEntryByOrder<K, V, TypeCompare<EK, K>>>::Output;
+}
+
+/// Internal dispatch for [`InsertEntry`] on the ordering of the head entry's
+/// key against the new key.
+pub trait InsertEntryByOrder<K, V, Ord>: HList {
+    /// The map with the entry inserted or updated.
+    type Output: HList;
+}
+
+impl<EK, EV, T, K, V> InsertEntryByOrder<K, V, Less> for HCons<KV<EK, EV>, T>
+where
+    // entry key < K -> keep the entry, insert further down
+    T: InsertEntry<K, V>,
+{
+    type Output = HCons<KV<EK, EV>, <T as InsertEntry<K, V>>::Output>;
+}
+
+impl<EK, EV, T: HList, K, V> InsertEntryByOrder<K, V, Equal> for HCons<KV<EK, EV>, T> {
+    // entry key == K -> replace the entry
+    type Output = HCons<KV<K, V>, T>;
+}
+
+impl<EK, EV, T: HList, K, V> InsertEntryByOrder<K, V, Greater> for HCons<KV<EK, EV>, T> {
+    // entry key > K -> the new entry goes first
+    type Output = HCons<KV<K, V>, Self>;
+}
+
+/// Merge two sorted maps, with entries of `Other` overriding those of `Self`.
+///
+/// The output contains every key of either map, sorted and (given
+/// duplicate-free inputs) duplicate-free.  When a key is present in both maps
+/// the entry from `Other` wins, which makes it easy to layer user overrides on
+/// top of a default map.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::{GetValue, MergeEntries, KV};
+/// # use typenum::{U0, U4, U8};
+/// type Defaults = mk_hlist!(KV<U0, u8>, KV<U4, u8>);
+/// type Overrides = mk_hlist!(KV<U4, u16>, KV<U8, u32>);
+/// type Config = <Defaults as MergeEntries<Overrides>>::Output;
+/// // Config = mk_hlist!(KV<U0, u8>, KV<U4, u16>, KV<U8, u32>)
+/// let value: <Config as GetValue<U4>>::Output = 0u16;
+/// ```
+pub trait MergeEntries<Other: HList>: HList {
+    /// The merged map.
+    type Output: HList;
+}
+
+impl<Other: HList> MergeEntries<Other> for HNil {
+    type Output = Other;
+}
+
+impl<H, T: HList> MergeEntries<HNil> for HCons<H, T> {
+    type Output = Self;
+}
+
+impl<HA, TA: HList, HB, TB: HList> MergeEntries<HCons<HB, TB>> for HCons<HA, TA>
+where
+    // Compare the two head entries, then dispatch
+    HA: TypeCmp<HB>,
+    Self: MergeEntriesByOrder<HCons<HB, TB>, TypeCompare<HA, HB>>,
+{
+    type Output = <Self as MergeEntriesByOrder<HCons<HB, TB>, TypeCompare<HA, HB>>>::Output;
+}
+
+/// Internal dispatch for [`MergeEntries`] on the ordering of the two head
+/// entries' keys.
+pub trait MergeEntriesByOrder<Rhs: HList, Ord>: HList {
+    /// The merged map.
+    type Output: HList;
+}
+
+impl<HA, TA: HList, HB, TB: HList> MergeEntriesByOrder<HCons<HB, TB>, Less> for HCons<HA, TA>
+where
+    // HA < HB -> take HA, merge TA with the right map
+    TA: MergeEntries<HCons<HB, TB>>,
+{
+    type Output = HCons<HA, <TA as MergeEntries<HCons<HB, TB>>>::Output>;
+}
+
+impl<HA, TA: HList, HB, TB: HList> MergeEntriesByOrder<HCons<HB, TB>, Greater> for HCons<HA, TA>
+where
+    // HA > HB -> take HB, merge the left map with TB
+    HCons<HA, TA>: MergeEntries<TB>,
+{
+    type Output = HCons<HB, <HCons<HA, TA> as MergeEntries<TB>>::Output>;
+}
+
+impl<HA, TA: HList, HB, TB: HList> MergeEntriesByOrder<HCons<HB, TB>, Equal> for HCons<HA, TA>
+where
+    // HA == HB -> the right entry overrides, merge TA with TB
+    TA: MergeEntries<TB>,
+{
+    type Output = HCons<HB, <TA as MergeEntries<TB>>::Output>;
+}
+
+/// Inner join of two sorted maps.
+///
+/// For every key present in both maps the output holds an entry
+/// `KV<K, TPair<VLeft, VRight>>` with both values, keyed by the left entry's
+/// key; keys present in only one map are dropped.  This is the intersection
+/// walk of [`Intersect`](crate::Intersect) with both values retained.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::{JoinEntries, KV};
+/// # use typenum::{U0, U4, U8};
+/// type Producers = mk_hlist!(KV<U0, u8>, KV<U4, u16>);
+/// type Consumers = mk_hlist!(KV<U4, i16>, KV<U8, i32>);
+/// type Wiring = <Producers as JoinEntries<Consumers>>::Output;
+/// // Wiring = mk_hlist!(KV<U4, TPair<u16, i16>>)
+/// ```
+pub trait JoinEntries<Other: HList>: HList {
+    /// The joined map.
+    type Output: HList;
+}
+
+impl<Other: HList> JoinEntries<Other> for HNil {
+    type Output = HNil;
+}
+
+impl<H, T: HList> JoinEntries<HNil> for HCons<H, T> {
+    type Output = HNil;
+}
+
+impl<HA, TA: HList, HB, TB: HList> JoinEntries<HCons<HB, TB>> for HCons<HA, TA>
+where
+    // Compare the two head entries, then dispatch
+    HA: TypeCmp<HB>,
+    Self: JoinEntriesByOrder<HCons<HB, TB>, TypeCompare<HA, HB>>,
+{
+    type Output = <Self as JoinEntriesByOrder<HCons<HB, TB>, TypeCompare<HA, HB>>>::Output;
+}
+
+/// Internal dispatch for [`JoinEntries`] on the ordering of the two head
+/// entries' keys.
+pub trait JoinEntriesByOrder<Rhs: HList, Ord>: HList {
+    /// The joined map.
+    type Output: HList;
+}
+
+impl<HA, TA: HList, HB, TB: HList> JoinEntriesByOrder<HCons<HB, TB>, Less> for HCons<HA, TA>
+where
+    // HA < HB -> drop HA, join TA with the right map
+    TA: JoinEntries<HCons<HB, TB>>,
+{
+    type Output = <TA as JoinEntries<HCons<HB, TB>>>::Output;
+}
+
+impl<HA, TA: HList, HB, TB: HList> JoinEntriesByOrder<HCons<HB, TB>, Greater> for HCons<HA, TA>
+where
+    // HA > HB -> drop HB, join the left map with TB
+    HCons<HA, TA>: JoinEntries<TB>,
+{
+    type Output = <HCons<HA, TA> as JoinEntries<TB>>::Output;
+}
+
+impl<KA, VA, TA: HList, KB, VB, TB: HList> JoinEntriesByOrder<HCons<KV<KB, VB>, TB>, Equal>
+    for HCons<KV<KA, VA>, TA>
+where
+    // keys match -> pair up both values, join TA with TB
+    TA: JoinEntries<TB>,
+{
+    type Output = HCons<KV<KA, TPair<VA, VB>>, <TA as JoinEntries<TB>>::Output>;
+}
@@ -0,0 +1,115 @@
+//! Sorted lists annotated with a metadata tag.
+
+use crate::{
+    DifferenceUnchecked, HList, IntersectUnchecked, Len, LowerBound, Member, SafeGet, SortedHList,
+    UnionUnchecked, UpperBound,
+};
+use core::marker::PhantomData;
+use typenum::Unsigned;
+
+/// A sorted list `List` annotated with a tag, e.g. a source identifier.
+///
+/// The tag does not affect the elements or their sortedness: `WithMeta` is a
+/// [`SortedHList`] whenever `List` is, and the set operations
+/// [`Intersect`](crate::Intersect), [`Union`](crate::Union) and
+/// [`Difference`](crate::Difference) are delegated to the inner list.
+/// Operations combining two `WithMeta` lists require both to carry the same
+/// `Tag`, and the result keeps it.
+///
+/// The queries [`Len`] (and with it the length bounds), [`SafeGet`],
+/// [`Member`], [`LowerBound`] and [`UpperBound`] are delegated too.  Any
+/// other list trait applies to the wrapped list, reached through
+/// [`InnerList`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::{InnerList, WithMeta};
+/// # use typenum::{U1, U2, U3};
+/// struct FromDatasheet;
+///
+/// type A = WithMeta<FromDatasheet, mk_hlist!(U1, U2)>;
+/// type B = WithMeta<FromDatasheet, mk_hlist!(U2, U3)>;
+/// type Common = InnerList<<A as Intersect<B>>::Output>;
+/// // Common = mk_hlist!(U2)
+/// ```
+///
+/// Lists with different tags cannot be combined:
+///
+/// ```rust,compile_fail
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::WithMeta;
+/// # use typenum::{U1, U2};
+/// struct Datasheet;
+/// struct Errata;
+///
+/// type A = WithMeta<Datasheet, mk_hlist!(U1, U2)>;
+/// type B = WithMeta<Errata, mk_hlist!(U2)>;
+/// type Common = <A as Intersect<B>>::Output;
+/// let _: Option<Common> = None;
+/// ```
+pub struct WithMeta<Tag, List: SortedHList>(PhantomData<(Tag, List)>);
+
+/// Access to the list wrapped by [`WithMeta`].
+pub trait HasList {
+    /// The wrapped list.
+    type List: SortedHList;
+}
+
+impl<Tag, List: SortedHList> HasList for WithMeta<Tag, List> {
+    type List = List;
+}
+
+/// Shorthand for the list wrapped by a [`HasList`] type.
+pub type InnerList<W> = <W as HasList>::List;
+
+impl<Tag, List: SortedHList> HList for WithMeta<Tag, List> {
+    const LEN: usize = List::LEN;
+}
+impl<Tag, List: SortedHList> SortedHList for WithMeta<Tag, List> {}
+
+impl<Tag, A, B> IntersectUnchecked<WithMeta<Tag, B>> for WithMeta<Tag, A>
+where
+    A: SortedHList + IntersectUnchecked<B>,
+    B: SortedHList,
+    <A as IntersectUnchecked<B>>::Output: SortedHList,
+{
+    type Output = WithMeta<Tag, <A as IntersectUnchecked<B>>::Output>;
+}
+
+impl<Tag, A, B> UnionUnchecked<WithMeta<Tag, B>> for WithMeta<Tag, A>
+where
+    A: SortedHList + UnionUnchecked<B>,
+    B: SortedHList,
+    <A as UnionUnchecked<B>>::Output: SortedHList,
+{
+    type Output = WithMeta<Tag, <A as UnionUnchecked<B>>::Output>;
+}
+
+impl<Tag, A, B> DifferenceUnchecked<WithMeta<Tag, B>> for WithMeta<Tag, A>
+where
+    A: SortedHList + DifferenceUnchecked<B>,
+    B: SortedHList,
+    <A as DifferenceUnchecked<B>>::Output: SortedHList,
+{
+    type Output = WithMeta<Tag, <A as DifferenceUnchecked<B>>::Output>;
+}
+
+impl<Tag, List: SortedHList + Len> Len for WithMeta<Tag, List> {
+    type Output = <List as Len>::Output;
+}
+
+impl<Tag, List: SortedHList + SafeGet<N>, N: Unsigned> SafeGet<N> for WithMeta<Tag, List> {
+    type Output = <List as SafeGet<N>>::Output;
+}
+
+impl<Tag, List: SortedHList + Member<X>, X> Member<X> for WithMeta<Tag, List> {}
+
+impl<Tag, List: SortedHList + LowerBound<T>, T> LowerBound<T> for WithMeta<Tag, List> {
+    type Output = <List as LowerBound<T>>::Output;
+}
+
+impl<Tag, List: SortedHList + UpperBound<T>, T> UpperBound<T> for WithMeta<Tag, List> {
+    type Output = <List as UpperBound<T>>::Output;
+}
@@ -0,0 +1,256 @@
+//! Readable names of list types for diagnostics.
+//!
+//! Element types name themselves through [`TypeName::TYPE_NAME`].  A whole
+//! list is named either by [`TypeRepr::TYPE_REPR`], a `const &'static str`
+//! assembled at compile time in a fixed-size buffer, or by
+//! [`type_list_name`], a zero-sized value whose `Display` output spells out
+//! the list structure without a length limit.
+
+use crate::{HCons, HList, HNil};
+use core::fmt;
+use core::marker::PhantomData;
+use typenum::{Bit, NInt, NonZero, PInt, UInt, UTerm, Unsigned, B0, B1, Z0};
+
+/// A short, human-readable name for an element type.
+///
+/// Implemented for the primitive types, the `typenum` bits and every
+/// `typenum` integer whose magnitude is at most `u64::MAX`, which is named
+/// in decimal (`U0`, `U1000`, `Z0`, `P7`, `N3`, ...); implement it for your
+/// own marker types to print lists of them.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::{type_list_name, TypeName};
+/// struct CapRead;
+///
+/// impl TypeName for CapRead {
+///     const TYPE_NAME: &'static str = "CapRead";
+/// }
+///
+/// let name = format!("{}", type_list_name::<mk_hlist!(CapRead, u8)>());
+/// assert_eq!(name, "HCons<CapRead, HCons<u8, HNil>>");
+/// ```
+pub trait TypeName {
+    /// The name of the type.
+    const TYPE_NAME: &'static str;
+}
+
+macro_rules! primitive_names {
+    ($($ty:ty),*) => {
+        $(impl TypeName for $ty {
+            const TYPE_NAME: &'static str = stringify!($ty);
+        })*
+    };
+}
+
+primitive_names!(
+    (),
+    bool,
+    char,
+    str,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    f32,
+    f64
+);
+
+macro_rules! typenum_names {
+    ($($ty:ident),*) => {
+        $(impl TypeName for $ty {
+            const TYPE_NAME: &'static str = stringify!($ty);
+        })*
+    };
+}
+
+typenum_names!(B0, B1, Z0);
+
+impl TypeName for UTerm {
+    const TYPE_NAME: &'static str = "U0";
+}
+
+impl<U: Unsigned, B: Bit> TypeName for UInt<U, B> {
+    const TYPE_NAME: &'static str = decimal_name(&IntegerName::<Self, b'U'>::BYTES);
+}
+
+impl<U: Unsigned + NonZero> TypeName for PInt<U> {
+    const TYPE_NAME: &'static str = decimal_name(&IntegerName::<U, b'P'>::BYTES);
+}
+
+impl<U: Unsigned + NonZero> TypeName for NInt<U> {
+    const TYPE_NAME: &'static str = decimal_name(&IntegerName::<U, b'N'>::BYTES);
+}
+
+/// Room for a one-letter prefix and the 20 decimal digits of `u64::MAX`.
+const NAME_LEN: usize = 21;
+
+/// The name of a `typenum` integer with magnitude `N`, spelled out at
+/// compile time after the letter `PREFIX`.
+struct IntegerName<N, const PREFIX: u8>(PhantomData<N>);
+
+impl<N: Unsigned, const PREFIX: u8> IntegerName<N, PREFIX> {
+    /// `PREFIX` and the decimal digits of `N`, right-aligned, with the index
+    /// where the name starts.
+    const BYTES: ([u8; NAME_LEN], usize) = {
+        let mut bytes = [0; NAME_LEN];
+        let mut start = NAME_LEN;
+        let mut n = N::U64;
+        loop {
+            start -= 1;
+            bytes[start] = b'0' + (n % 10) as u8;
+            n /= 10;
+            if n == 0 {
+                break;
+            }
+        }
+        start -= 1;
+        bytes[start] = PREFIX;
+        (bytes, start)
+    };
+}
+
+/// The name held in the [`IntegerName`] bytes.
+const fn decimal_name(name: &'static ([u8; NAME_LEN], usize)) -> &'static str {
+    match core::str::from_utf8(name.0.split_at(name.1).1) {
+        Ok(name) => name,
+        Err(_) => panic!("decimal digits are valid UTF-8"),
+    }
+}
+
+/// The structure of a type as a `const &'static str`, e.g.
+/// `HCons<u8, HNil>`.
+///
+/// Implemented for `HNil`, for `HCons` lists of `TypeRepr` elements, and for
+/// every [`TypeName`] type, which is represented by its name.  The string is
+/// assembled at compile time in a buffer of 256 bytes; naming a longer list
+/// fails to compile, and [`type_list_name`] prints lists of any length.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::TypeRepr;
+/// # use typenum::{U1, U2};
+/// const NAME: &str = <HCons<u8, HNil> as TypeRepr>::TYPE_REPR;
+/// assert_eq!(NAME, "HCons<u8, HNil>");
+/// assert_eq!(
+///     <mk_hlist!(U1, mk_hlist!(U2)) as TypeRepr>::TYPE_REPR,
+///     "HCons<U1, HCons<HCons<U2, HNil>, HNil>>"
+/// );
+/// ```
+///
+/// ```rust,compile_fail
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::TypeRepr;
+/// type L = mk_hlist!(u128, u128, u128, u128, u128, u128, u128, u128, u128, u128, u128, u128,
+///                    u128, u128, u128, u128, u128, u128, u128, u128, u128, u128, u128, u128);
+/// const NAME: &str = <L as TypeRepr>::TYPE_REPR;
+/// # let _ = NAME;
+/// ```
+pub trait TypeRepr {
+    /// The structure of `Self`.
+    const TYPE_REPR: &'static str;
+}
+
+impl<T: TypeName> TypeRepr for T {
+    const TYPE_REPR: &'static str = T::TYPE_NAME;
+}
+
+impl TypeRepr for HNil {
+    const TYPE_REPR: &'static str = "HNil";
+}
+
+impl<H: TypeRepr, T: TypeRepr + HList> TypeRepr for HCons<H, T> {
+    const TYPE_REPR: &'static str = {
+        let (bytes, len) = &ConsRepr::<H, T>::BYTES;
+        match core::str::from_utf8(bytes.split_at(*len).0) {
+            Ok(repr) => repr,
+            Err(_) => panic!("joined names are valid UTF-8"),
+        }
+    };
+}
+
+/// The capacity of a [`TypeRepr`] string in bytes.
+const REPR_LEN: usize = 256;
+
+/// The representation of `HCons<H, T>`, assembled at compile time.
+struct ConsRepr<H, T>(PhantomData<(H, T)>);
+
+impl<H: TypeRepr, T: TypeRepr> ConsRepr<H, T> {
+    /// `HCons<{H}, {T}>` at the start of the buffer, with its length.
+    const BYTES: ([u8; REPR_LEN], usize) = {
+        let parts = [
+            "HCons<".as_bytes(),
+            H::TYPE_REPR.as_bytes(),
+            ", ".as_bytes(),
+            T::TYPE_REPR.as_bytes(),
+            ">".as_bytes(),
+        ];
+        let mut bytes = [0; REPR_LEN];
+        let mut len = 0;
+        let mut p = 0;
+        while p < parts.len() {
+            let part = parts[p];
+            let mut i = 0;
+            while i < part.len() {
+                if len == REPR_LEN {
+                    panic!("TYPE_REPR is longer than 256 bytes");
+                }
+                bytes[len] = part[i];
+                len += 1;
+                i += 1;
+            }
+            p += 1;
+        }
+        (bytes, len)
+    };
+}
+
+/// Writes the structure of a list whose elements implement [`TypeName`].
+pub trait ListName: HList {
+    /// Write the name of `Self`, e.g. `HCons<u8, HNil>`.
+    fn fmt_list_name(f: &mut fmt::Formatter<'_>) -> fmt::Result;
+}
+
+impl ListName for HNil {
+    fn fmt_list_name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("HNil")
+    }
+}
+
+impl<H: TypeName, T: ListName> ListName for HCons<H, T> {
+    fn fmt_list_name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "HCons<{}, ", H::TYPE_NAME)?;
+        T::fmt_list_name(f)?;
+        f.write_str(">")
+    }
+}
+
+/// The name of `L`, returned by [`type_list_name`].
+pub struct TypeListName<L: ListName>(PhantomData<L>);
+
+impl<L: ListName> fmt::Display for TypeListName<L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        L::fmt_list_name(f)
+    }
+}
+
+/// The name of `L` for logging, e.g. `HCons<u8, HCons<bool, HNil>>`.
+///
+/// Unlike `core::any::type_name`, the output is independent of module
+/// paths and compiler version.
+#[must_use = "this type-level computation produces an output; ignoring it is likely a mistake"]
+pub const fn type_list_name<L: ListName>() -> TypeListName<L> {
+    TypeListName(PhantomData)
+}
@@ -0,0 +1,70 @@
+//! Operator shorthand for the set operations on sorted lists.
+//!
+//! `&`, `|` and `-` on list values are [`Intersect`], [`Union`] and
+//! [`Difference`].  At the type level, typenum's `And`, `Or` and `Diff`
+//! aliases then name the results:
+//!
+//! ```rust
+//! # use sorted_hlist::prelude::*;
+//! # use typenum::{U1, U2, U3, U4};
+//! use typenum::{And, Diff, Or};
+//!
+//! type A = mk_hlist!(U1, U2, U3);
+//! type B = mk_hlist!(U2, U4);
+//! assert_type_eq::<And<A, B>, mk_hlist!(U2)>();
+//! assert_type_eq::<Or<A, B>, mk_hlist!(U1, U2, U3, U4)>();
+//! assert_type_eq::<Diff<A, B>, mk_hlist!(U1, U3)>();
+//!
+//! let common = A::new() & B::new();
+//! assert_eq!(common, <mk_hlist!(U2)>::new());
+//! ```
+
+use crate::{Difference, HCons, HNil, Intersect, SortedHList, Union};
+use core::ops::{BitAnd, BitOr, Sub};
+
+macro_rules! set_operators {
+    ($([$($param:ident),*] $list:ty),*) => {
+        $(
+            impl<$($param,)* Rhs> BitAnd<Rhs> for $list
+            where
+                Self: Intersect<Rhs>,
+                Rhs: SortedHList,
+                <Self as Intersect<Rhs>>::Output: Default,
+            {
+                type Output = <Self as Intersect<Rhs>>::Output;
+
+                fn bitand(self, _: Rhs) -> Self::Output {
+                    Default::default()
+                }
+            }
+
+            impl<$($param,)* Rhs> BitOr<Rhs> for $list
+            where
+                Self: SortedHList + Union<Rhs>,
+                Rhs: SortedHList,
+                <Self as Union<Rhs>>::Output: Default,
+            {
+                type Output = <Self as Union<Rhs>>::Output;
+
+                fn bitor(self, _: Rhs) -> Self::Output {
+                    Default::default()
+                }
+            }
+
+            impl<$($param,)* Rhs> Sub<Rhs> for $list
+            where
+                Self: SortedHList + Difference<Rhs>,
+                Rhs: SortedHList,
+                <Self as Difference<Rhs>>::Output: Default,
+            {
+                type Output = <Self as Difference<Rhs>>::Output;
+
+                fn sub(self, _: Rhs) -> Self::Output {
+                    Default::default()
+                }
+            }
+        )*
+    };
+}
+
+set_operators!([] HNil, [H, T] HCons<H, T>);
@@ -0,0 +1,52 @@
+//! Two sorted lists bundled into one type parameter.
+
+use crate::{Intersect, SortedHList, Union};
+use core::marker::PhantomData;
+
+/// Two sorted lists `LA` and `LB` carried as a single type, e.g. one
+/// generic parameter or `PhantomData` marker.
+///
+/// The lists and their combinations are reached through [`ListPair`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use core::marker::PhantomData;
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::{ListPair, SortedHListPair};
+/// # use typenum::{U1, U2, U3, U4};
+/// type Caps = SortedHListPair<mk_hlist!(U1, U2, U3), mk_hlist!(U2, U3, U4)>;
+///
+/// fn common<P: ListPair>() -> PhantomData<P::Intersection> {
+///     PhantomData
+/// }
+///
+/// let _: PhantomData<mk_hlist!(U2, U3)> = common::<Caps>();
+/// ```
+pub struct SortedHListPair<LA: SortedHList, LB: SortedHList>(PhantomData<(LA, LB)>);
+
+/// Access to the lists bundled in a [`SortedHListPair`] and to their
+/// intersection and union.
+pub trait ListPair {
+    /// The first list.
+    type Left: SortedHList;
+    /// The second list.
+    type Right: SortedHList;
+    /// The sorted intersection of `Left` and `Right`.
+    type Intersection: SortedHList;
+    /// The sorted union of `Left` and `Right`.
+    type Union: SortedHList;
+}
+
+impl<LA, LB> ListPair for SortedHListPair<LA, LB>
+where
+    LA: SortedHList + Intersect<LB> + Union<LB>,
+    LB: SortedHList,
+    <LA as Intersect<LB>>::Output: SortedHList,
+    <LA as Union<LB>>::Output: SortedHList,
+{
+    type Left = LA;
+    type Right = LB;
+    type Intersection = <LA as Intersect<LB>>::Output;
+    type Union = <LA as Union<LB>>::Output;
+}
@@ -0,0 +1,125 @@
+//! Type-level diffs between two versions of a sorted list.
+
+use crate::{Difference, SortedHList, SubsetOf, Union};
+use core::marker::PhantomData;
+
+/// The change from the sorted list `Before` to the sorted list `After`.
+///
+/// Its [`Patch`] impl gives the elements added and removed, and
+/// [`ApplyPatch`] replays the change on `Before`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::{ApplyPatch, DiffPatch, Patch};
+/// # use typenum::{U1, U2, U3, U4};
+/// type V1 = mk_hlist!(U1, U2, U3);
+/// type V2 = mk_hlist!(U2, U3, U4);
+/// type Change = DiffPatch<V1, V2>;
+///
+/// assert_type_eq::<<Change as Patch>::Added, mk_hlist!(U4)>();
+/// assert_type_eq::<<Change as Patch>::Removed, mk_hlist!(U1)>();
+/// assert_type_eq::<<V1 as ApplyPatch<Change>>::Output, V2>();
+/// ```
+pub struct DiffPatch<Before: SortedHList, After: SortedHList>(PhantomData<(Before, After)>);
+
+/// A change to a sorted list, as the sorted sets of added and removed
+/// elements.
+pub trait Patch {
+    /// The elements to add.
+    type Added: SortedHList;
+    /// The elements to remove.
+    type Removed: SortedHList;
+}
+
+impl<Before, After> Patch for DiffPatch<Before, After>
+where
+    Before: SortedHList + Difference<After>,
+    After: SortedHList + Difference<Before>,
+{
+    type Added = <After as Difference<Before>>::Output;
+    type Removed = <Before as Difference<After>>::Output;
+}
+
+/// `Self` with the [`Patch`] `P` applied: its removed elements dropped and
+/// its added elements merged in.
+///
+/// The only check is that the removed elements are all present in `Self`.
+/// A patch can therefore be applied to any list containing them, not just
+/// the `Before` it was computed from; a list missing one of them is
+/// rejected:
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::{ApplyPatch, DiffPatch};
+/// # use typenum::{U1, U2, U9};
+/// type Change = DiffPatch<mk_hlist!(U1, U2), mk_hlist!(U2)>;
+/// assert_type_eq::<<mk_hlist!(U1, U9) as ApplyPatch<Change>>::Output, mk_hlist!(U9)>();
+/// ```
+///
+/// ```rust,compile_fail
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::{ApplyPatch, DiffPatch};
+/// # use typenum::{U1, U2, U3};
+/// type Change = DiffPatch<mk_hlist!(U1, U2), mk_hlist!(U2)>;
+/// fn apply<L: ApplyPatch<Change>>() {}
+/// apply::<mk_hlist!(U2, U3)>();
+/// ```
+pub trait ApplyPatch<P: Patch>: SortedHList {
+    /// The patched list.
+    type Output: SortedHList;
+}
+
+impl<L, P> ApplyPatch<P> for L
+where
+    // drop the removed elements, then merge in the added ones
+    P: Patch,
+    P::Removed: SubsetOf<L>,
+    L: SortedHList + Difference<P::Removed>,
+    <L as Difference<P::Removed>>::Output: Union<P::Added>,
+{
+    type Output = <<L as Difference<P::Removed>>::Output as Union<P::Added>>::Output;
+}
+
+/// Witness of the [`DiffPatch`] between the witnesses of two sorted lists.
+#[must_use = "this type-level computation produces an output; ignoring it is likely a mistake"]
+pub const fn diff_patch<Before, After>(
+    _before: PhantomData<Before>,
+    _after: PhantomData<After>,
+) -> PhantomData<DiffPatch<Before, After>>
+where
+    Before: SortedHList,
+    After: SortedHList,
+{
+    PhantomData
+}
+
+/// Apply the witness of a patch `P` to the witness of a sorted list,
+/// returning the witness of the patched list.
+///
+/// ```rust
+/// # use core::marker::PhantomData;
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::{apply_patch, diff_patch, DiffPatch};
+/// # use typenum::{U1, U2, U3, U4};
+/// let v1 = is_sorted_witness::<mk_hlist!(U1, U2)>();
+/// let v2 = is_sorted_witness::<mk_hlist!(U2, U3)>();
+/// let change = diff_patch(v1, v2);
+/// let _: PhantomData<mk_hlist!(U2, U3)> = apply_patch(v1, change);
+///
+/// // a named patch works the same way
+/// type AddFour = DiffPatch<mk_hlist!(), mk_hlist!(U4)>;
+/// let _: PhantomData<mk_hlist!(U1, U2, U4)> = apply_patch(v1, PhantomData::<AddFour>);
+/// ```
+#[must_use = "this type-level computation produces an output; ignoring it is likely a mistake"]
+pub const fn apply_patch<L, P>(
+    _list: PhantomData<L>,
+    _patch: PhantomData<P>,
+) -> PhantomData<<L as ApplyPatch<P>>::Output>
+where
+    L: ApplyPatch<P>,
+    P: Patch,
+{
+    PhantomData
+}
@@ -0,0 +1,285 @@
+//! Type-level predicates on list elements and the operations driven by them.
+
+use crate::{HCons, HList, HNil, TNone, TOption, TSome, KV};
+use core::marker::PhantomData;
+use core::ops::{Add, BitAnd, BitOr, Rem};
+use typenum::{Add1, And, Bit, IsEqual, IsGreater, IsLess, Mod, Or, Unsigned, B0, B1, U0, U2};
+
+/// A type-level predicate on `T`, answering with a `typenum` `Bit`.
+///
+/// Implement it on a marker type for every element type it accepts.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::{Filter, TypePred};
+/// # use typenum::{B0, B1};
+/// struct IsUnsigned;
+///
+/// impl TypePred<u8> for IsUnsigned {
+///     type Output = B1;
+/// }
+///
+/// impl TypePred<i8> for IsUnsigned {
+///     type Output = B0;
+/// }
+///
+/// assert_type_eq::<<mk_hlist!(i8, u8, i8) as Filter<IsUnsigned>>::Output, mk_hlist!(u8)>();
+/// ```
+pub trait TypePred<T> {
+    /// `B1` if `T` satisfies the predicate, `B0` otherwise.
+    type Output: Bit;
+}
+
+/// Holds for even `typenum` unsigned integers.
+pub struct IsEven;
+
+impl<T> TypePred<T> for IsEven
+where
+    T: Rem<U2>,
+    Mod<T, U2>: IsEqual<U0>,
+{
+    type Output = <Mod<T, U2> as IsEqual<U0>>::Output;
+}
+
+/// Holds for `typenum` integers greater than `N`.
+pub struct GreaterThan<N>(PhantomData<N>);
+
+impl<T: IsGreater<N>, N> TypePred<T> for GreaterThan<N> {
+    type Output = <T as IsGreater<N>>::Output;
+}
+
+/// Holds for `typenum` integers less than `N`.
+pub struct LessThan<N>(PhantomData<N>);
+
+impl<T: IsLess<N>, N> TypePred<T> for LessThan<N> {
+    type Output = <T as IsLess<N>>::Output;
+}
+
+/// Holds for `typenum` integers equal to `N`.
+pub struct EqualTo<N>(PhantomData<N>);
+
+impl<T: IsEqual<N>, N> TypePred<T> for EqualTo<N> {
+    type Output = <T as IsEqual<N>>::Output;
+}
+
+/// Holds for `KV<K, V>` entries whose value satisfies `P`, e.g. to filter
+/// the output of [`Enumerate`](crate::Enumerate) by element.
+pub struct OnValue<P>(PhantomData<P>);
+
+impl<P: TypePred<V>, K, V> TypePred<KV<K, V>> for OnValue<P> {
+    type Output = <P as TypePred<V>>::Output;
+}
+
+/// Whether every element of an HList satisfies `P`; `B1` for `HNil`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::{All, LessThan};
+/// # use typenum::{Bit, U12, U3, U31, U32};
+/// // register numbers that fit in a 5-bit field
+/// type Regs = mk_hlist!(U3, U12, U31);
+/// const _: () = assert!(<Regs as All<LessThan<U32>>>::Output::BOOL);
+/// ```
+pub trait All<P>: HList {
+    /// `B1` if all elements satisfy `P`, `B0` otherwise.
+    type Output: Bit;
+}
+
+impl<P> All<P> for HNil {
+    type Output = B1;
+}
+
+impl<P, H, T> All<P> for HCons<H, T>
+where
+    // the head satisfies P and so does the whole tail
+    P: TypePred<H>,
+    T: All<P>,
+    <P as TypePred<H>>::Output: BitAnd<<T as All<P>>::Output>,
+    And<<P as TypePred<H>>::Output, <T as All<P>>::Output>: Bit,
+{
+    type Output = And<<P as TypePred<H>>::Output, <T as All<P>>::Output>;
+}
+
+/// Whether some element of an HList satisfies `P`; `B0` for `HNil`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::{Any, IsEven};
+/// # use typenum::{Bit, U1, U4, U5};
+/// const _: () = assert!(<mk_hlist!(U1, U4, U5) as Any<IsEven>>::Output::BOOL);
+/// const _: () = assert!(!<mk_hlist!(U1, U5) as Any<IsEven>>::Output::BOOL);
+/// ```
+pub trait Any<P>: HList {
+    /// `B1` if some element satisfies `P`, `B0` otherwise.
+    type Output: Bit;
+}
+
+impl<P> Any<P> for HNil {
+    type Output = B0;
+}
+
+impl<P, H, T> Any<P> for HCons<H, T>
+where
+    // the head satisfies P or something in the tail does
+    P: TypePred<H>,
+    T: Any<P>,
+    <P as TypePred<H>>::Output: BitOr<<T as Any<P>>::Output>,
+    Or<<P as TypePred<H>>::Output, <T as Any<P>>::Output>: Bit,
+{
+    type Output = Or<<P as TypePred<H>>::Output, <T as Any<P>>::Output>;
+}
+
+/// The elements of an HList that satisfy `P`, in their original order.
+///
+/// Filtering a [`SortedHList`](crate::SortedHList) yields a sorted list.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::{Filter, IsEven};
+/// # use typenum::{U1, U2, U3, U4};
+/// type Evens = <mk_hlist!(U1, U2, U3, U4) as Filter<IsEven>>::Output;
+/// // Evens = mk_hlist!(U2, U4)
+/// ```
+pub trait Filter<P>: HList {
+    /// The elements satisfying `P`.
+    type Output: HList;
+}
+
+impl<P> Filter<P> for HNil {
+    type Output = HNil;
+}
+
+impl<P, H, T: HList> Filter<P> for HCons<H, T>
+where
+    // Test the head, then dispatch
+    P: TypePred<H>,
+    Self: FilterByBit<P, <P as TypePred<H>>::Output>,
+{
+    type Output = <Self as FilterByBit<P, <P as TypePred<H>>::Output>>::Output;
+}
+
+/// Internal dispatch for [`Filter`] on the predicate's answer for the head.
+pub trait FilterByBit<P, B: Bit>: HList {
+    /// The elements satisfying `P`.
+    type Output: HList;
+}
+
+impl<P, H, T: Filter<P>> FilterByBit<P, B1> for HCons<H, T> {
+    // keep H
+    type Output = HCons<H, <T as Filter<P>>::Output>;
+}
+
+impl<P, H, T: Filter<P>> FilterByBit<P, B0> for HCons<H, T> {
+    // drop H
+    type Output = <T as Filter<P>>::Output;
+}
+
+/// The number of elements of an HList that satisfy `P`, as a `typenum`
+/// `Unsigned`.  Equal to the [`Len`](crate::Len) of the [`Filter`] output.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::{CountWhere, IsEven};
+/// # use typenum::{Unsigned, U1, U2, U3, U4};
+/// type Evens = <mk_hlist!(U1, U2, U3, U4) as CountWhere<IsEven>>::Output;
+/// let slots = [0u8; Evens::USIZE];
+/// assert_eq!(slots.len(), 2);
+/// ```
+pub trait CountWhere<P>: HList {
+    /// The number of elements satisfying `P`.
+    type Output: Unsigned;
+}
+
+impl<P> CountWhere<P> for HNil {
+    type Output = U0;
+}
+
+impl<P, H, T: HList> CountWhere<P> for HCons<H, T>
+where
+    // Test the head, then dispatch
+    P: TypePred<H>,
+    Self: CountWhereByBit<P, <P as TypePred<H>>::Output>,
+{
+    type Output = <Self as CountWhereByBit<P, <P as TypePred<H>>::Output>>::Output;
+}
+
+/// Internal dispatch for [`CountWhere`] on the predicate's answer for the
+/// head.
+pub trait CountWhereByBit<P, B: Bit>: HList {
+    /// The number of elements satisfying `P`.
+    type Output: Unsigned;
+}
+
+impl<P, H, T> CountWhereByBit<P, B1> for HCons<H, T>
+where
+    // count H
+    T: CountWhere<P>,
+    <T as CountWhere<P>>::Output: Add<B1>,
+    Add1<<T as CountWhere<P>>::Output>: Unsigned,
+{
+    type Output = Add1<<T as CountWhere<P>>::Output>;
+}
+
+impl<P, H, T: CountWhere<P>> CountWhereByBit<P, B0> for HCons<H, T> {
+    // skip H
+    type Output = <T as CountWhere<P>>::Output;
+}
+
+/// The first element of an HList that satisfies `P`, as [`TSome`], or
+/// [`TNone`] if there is none.
+///
+/// Unlike a lookup that fails to compile, `Find` is defined for every list,
+/// and the result can be dispatched on further.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::{Find, IsEven, TNone, TSome};
+/// # use typenum::{U1, U3, U4, U6};
+/// assert_type_eq::<<mk_hlist!(U1, U4, U6) as Find<IsEven>>::Output, TSome<U4>>();
+/// assert_type_eq::<<mk_hlist!(U1, U3) as Find<IsEven>>::Output, TNone>();
+/// ```
+pub trait Find<P>: HList {
+    /// The first element satisfying `P`, if any.
+    type Output: TOption;
+}
+
+impl<P> Find<P> for HNil {
+    type Output = TNone;
+}
+
+impl<P, H, T: HList> Find<P> for HCons<H, T>
+where
+    // Test the head, then dispatch
+    P: TypePred<H>,
+    Self: FindByBit<P, <P as TypePred<H>>::Output>,
+{
+    type Output = <Self as FindByBit<P, <P as TypePred<H>>::Output>>::Output;
+}
+
+/// Internal dispatch for [`Find`] on the predicate's answer for the head.
+pub trait FindByBit<P, B: Bit>: HList {
+    /// The first element satisfying `P`, if any.
+    type Output: TOption;
+}
+
+impl<P, H, T: HList> FindByBit<P, B1> for HCons<H, T> {
+    // found H
+    type Output = TSome<H>;
+}
+
+impl<P, H, T: Find<P>> FindByBit<P, B0> for HCons<H, T> {
+    // keep looking in the tail
+    type Output = <T as Find<P>>::Output;
+}
@@ -0,0 +1,27 @@
+//! The core items for everyday use, in one glob import.
+//!
+//! ```rust
+//! use sorted_hlist::prelude::*;
+//! use typenum::{U1, U2, U3, U4};
+//!
+//! type Common = <mk_hlist!(U1, U2, U3) as Intersect<mk_hlist!(U2, U3, U4)>>::Output;
+//! assert_type_eq::<Common, mk_hlist!(U2, U3)>();
+//! ```
+//!
+//! The prelude holds the list types and marker traits, the comparator, the
+//! set operations [`Intersect`], [`IntersectUnchecked`], [`Union`] and
+//! [`Difference`], and the witness functions and assertions.  It is kept small
+//! so that it can be glob-imported next to other crates, `typenum` in
+//! particular: every other operation, and the `typenum` constants, are
+//! imported by name.
+
+pub use crate::mk_hlist;
+
+pub use crate::{HCons, HList, HNil, NonEmptyHList, SortedHList, TypeCmp};
+
+pub use crate::{Difference, Intersect, IntersectUnchecked, Union};
+
+pub use crate::{
+    assert_sorted, assert_type_eq, difference, intersect, is_sorted_witness, union, AssertLen,
+    AssertSorted, TypeEq,
+};
@@ -0,0 +1,226 @@
+//! Run-length encoding of lists into maps of multiplicities.
+//!
+//! A list with repeated elements is encoded as a list of [`KV`] entries
+//! from each run's element to its `typenum` count.  For sorted input the
+//! keys of the encoding are strictly increasing, making it a map.
+//! [`RunLengthDecode`] expands an encoding back into the original list, and
+//! [`GroupConsecutive`] keeps the runs as sub-lists instead of counting
+//! them.
+//!
+//! The encoding does not go through `GroupConsecutive`: [`PushRun`] counts
+//! each run directly while walking the list, with the same run boundaries.
+//! Entries are `KV` pairs rather than `(T, N)` tuples so that an encoding
+//! works with [`Keys`](crate::Keys), [`Values`](crate::Values) and the other
+//! map traits.
+
+use crate::{HCons, HList, HNil, TypeCmp, TypeCompare, KV};
+use core::ops::{Add, Sub};
+use typenum::{Add1, Bit, Equal, Greater, Less, Sub1, UInt, Unsigned, B1, U0, U1};
+
+/// Run-length encode a list into `KV<Element, Count>` entries.
+///
+/// Adjacent elements comparing `Equal` form one run, keyed by its last
+/// element, as in [`GroupConsecutive`].  For sorted input each element gets
+/// a single entry; an unsorted list may repeat keys in separate runs.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::{RunLengthEncode, KV};
+/// # use typenum::{U1, U2, U3};
+/// type Runs = <mk_hlist!(U1, U1, U2, U3, U3, U3) as RunLengthEncode>::Output;
+/// assert_type_eq::<Runs, mk_hlist!(KV<U1, U2>, KV<U2, U1>, KV<U3, U3>)>();
+/// ```
+pub trait RunLengthEncode: HList {
+    /// The runs of `Self`.
+    type Output: HList;
+}
+
+impl RunLengthEncode for HNil {
+    type Output = HNil;
+}
+
+impl<H, T> RunLengthEncode for HCons<H, T>
+where
+    // encode the tail, then add the head to its first run
+    T: RunLengthEncode,
+    <T as RunLengthEncode>::Output: PushRun<H>,
+{
+    type Output = <<T as RunLengthEncode>::Output as PushRun<H>>::Output;
+}
+
+/// Internal helper for [`RunLengthEncode`]: adds one occurrence of `X` in
+/// front of an encoding.
+pub trait PushRun<X>: HList {
+    /// The encoding with `X` prepended.
+    type Output: HList;
+}
+
+impl<X> PushRun<X> for HNil {
+    type Output = HCons<KV<X, U1>, HNil>;
+}
+
+impl<X, K, C, R: HList> PushRun<X> for HCons<KV<K, C>, R>
+where
+    // Compare X against the key of the first run, then dispatch
+    X: TypeCmp<K>,
+    Self: PushRunByOrder<X, TypeCompare<X, K>>,
+{
+    type Output = <Self as PushRunByOrder<X, TypeCompare<X, K>>>::Output;
+}
+
+/// Internal dispatch for [`PushRun`] on the ordering of the new element
+/// against the key of the first run.
+pub trait PushRunByOrder<X, Ord>: HList {
+    /// The encoding with `X` prepended.
+    type Output: HList;
+}
+
+impl<X, K, C, R: HList> PushRunByOrder<X, Less> for HCons<KV<K, C>, R> {
+    // X < K -> X starts a new run
+    type Output = HCons<KV<X, U1>, Self>;
+}
+
+impl<X, K, C, R: HList> PushRunByOrder<X, Greater> for HCons<KV<K, C>, R> {
+    // X > K -> X starts a new run
+    type Output = HCons<KV<X, U1>, Self>;
+}
+
+impl<X, K, C, R> PushRunByOrder<X, Equal> for HCons<KV<K, C>, R>
+where
+    // X == K -> X extends the first run
+    C: Unsigned + Add<B1>,
+    Add1<C>: Unsigned,
+    R: HList,
+{
+    type Output = HCons<KV<K, Add1<C>>, R>;
+}
+
+/// Expand `KV<Element, Count>` entries into `Count` copies of each element.
+///
+/// The inverse of [`RunLengthEncode`]: for entries with strictly increasing
+/// keys the output is a [`SortedHList`](crate::SortedHList).  A count of
+/// `U0` expands to nothing.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::{RunLengthDecode, KV};
+/// # use typenum::{U0, U1, U2, U3};
+/// type L = <mk_hlist!(KV<U1, U2>, KV<U2, U0>, KV<U3, U1>) as RunLengthDecode>::Output;
+/// assert_type_eq::<L, mk_hlist!(U1, U1, U3)>();
+/// ```
+pub trait RunLengthDecode: HList {
+    /// The expanded list.
+    type Output: HList;
+}
+
+impl RunLengthDecode for HNil {
+    type Output = HNil;
+}
+
+impl<K, C, R> RunLengthDecode for HCons<KV<K, C>, R>
+where
+    // decode the remaining runs, then prepend this one
+    C: Unsigned,
+    R: RunLengthDecode,
+    <R as RunLengthDecode>::Output: PrependRun<K, C>,
+{
+    type Output = <<R as RunLengthDecode>::Output as PrependRun<K, C>>::Output;
+}
+
+/// Internal helper for [`RunLengthDecode`]: prepends `N` copies of `X`.
+pub trait PrependRun<X, N: Unsigned>: HList {
+    /// `Self` with `N` copies of `X` in front.
+    type Output: HList;
+}
+
+impl<X, L: HList> PrependRun<X, U0> for L {
+    type Output = L;
+}
+
+impl<X, L, U: Unsigned, B: Bit> PrependRun<X, UInt<U, B>> for L
+where
+    // one copy of X in front of N - 1 more
+    UInt<U, B>: Sub<B1>,
+    Sub1<UInt<U, B>>: Unsigned,
+    L: PrependRun<X, Sub1<UInt<U, B>>>,
+{
+    type Output = HCons<X, <L as PrependRun<X, Sub1<UInt<U, B>>>>::Output>;
+}
+
+/// Group runs of adjacent elements comparing `Equal` into sub-lists.
+///
+/// The input need not be sorted; equal elements that are not adjacent end
+/// up in separate groups.  [`Flatten`](crate::Flatten) undoes the grouping.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::GroupConsecutive;
+/// # use typenum::{U1, U2};
+/// type Groups = <mk_hlist!(U1, U1, U2, U1) as GroupConsecutive>::Output;
+/// assert_type_eq::<Groups, mk_hlist!(mk_hlist!(U1, U1), mk_hlist!(U2), mk_hlist!(U1))>();
+/// ```
+pub trait GroupConsecutive: HList {
+    /// The list of groups.
+    type Output: HList;
+}
+
+impl GroupConsecutive for HNil {
+    type Output = HNil;
+}
+
+impl<H, T> GroupConsecutive for HCons<H, T>
+where
+    // group the tail, then add the head to its first group
+    T: GroupConsecutive,
+    <T as GroupConsecutive>::Output: PushGroup<H>,
+{
+    type Output = <<T as GroupConsecutive>::Output as PushGroup<H>>::Output;
+}
+
+/// Internal helper for [`GroupConsecutive`]: adds `X` in front of a list of
+/// groups.
+pub trait PushGroup<X>: HList {
+    /// The groups with `X` prepended.
+    type Output: HList;
+}
+
+impl<X> PushGroup<X> for HNil {
+    type Output = HCons<HCons<X, HNil>, HNil>;
+}
+
+impl<X, G, GT: HList, R: HList> PushGroup<X> for HCons<HCons<G, GT>, R>
+where
+    // Compare X against the head of the first group, then dispatch
+    X: TypeCmp<G>,
+    Self: PushGroupByOrder<X, TypeCompare<X, G>>,
+{
+    type Output = <Self as PushGroupByOrder<X, TypeCompare<X, G>>>::Output;
+}
+
+/// Internal dispatch for [`PushGroup`] on the ordering of the new element
+/// against the head of the first group.
+pub trait PushGroupByOrder<X, Ord>: HList {
+    /// The groups with `X` prepended.
+    type Output: HList;
+}
+
+impl<X, G, GT: HList, R: HList> PushGroupByOrder<X, Equal> for HCons<HCons<G, GT>, R> {
+    // X == G -> X joins the first group
+    type Output = HCons<HCons<X, HCons<G, GT>>, R>;
+}
+
+impl<X, G, GT: HList, R: HList> PushGroupByOrder<X, Less> for HCons<HCons<G, GT>, R> {
+    // X < G -> X starts a new group
+    type Output = HCons<HCons<X, HNil>, Self>;
+}
+
+impl<X, G, GT: HList, R: HList> PushGroupByOrder<X, Greater> for HCons<HCons<G, GT>, R> {
+    // X > G -> X starts a new group
+    type Output = HCons<HCons<X, HNil>, Self>;
+}
@@ -0,0 +1,1107 @@
+//! Set operations and predicates on sorted HLists.
+//!
+//! Predicates yield a `typenum` `Bit` (`B1` for true, `B0` for false) rather
+//! than failing to compile, so their results can be combined and dispatched
+//! on.  The exceptions are [`Member`] and [`SubsetOf`], bounds that only
+//! hold for lists containing the elements.
+
+use crate::{
+    HCons, HList, HNil, Keys, MergeSorted, RunLengthEncode, Sort, SortedHList, TypeBinaryFn,
+    TypeCmp, TypeCompare, KV,
+};
+use core::ops::{Add, BitAnd};
+use typenum::{Add1, And, Bit, Equal, GrEq, Greater, IsGreaterOrEqual, Less, Unsigned, B0, B1, U0};
+
+/// Whether two sorted lists have no element in common.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::IsDisjoint;
+/// # use typenum::{Bit, U1, U2, U3, U4};
+/// assert!(<mk_hlist!(U1, U3) as IsDisjoint<mk_hlist!(U2, U4)>>::Output::BOOL);
+/// assert!(!<mk_hlist!(U1, U3) as IsDisjoint<mk_hlist!(U3, U4)>>::Output::BOOL);
+/// ```
+pub trait IsDisjoint<Other: HList>: HList {
+    /// `B1` if the lists are disjoint, `B0` otherwise.
+    type Output: Bit;
+}
+
+impl<Other: HList> IsDisjoint<Other> for HNil {
+    type Output = B1;
+}
+
+impl<H, T: HList> IsDisjoint<HNil> for HCons<H, T> {
+    type Output = B1;
+}
+
+impl<HA, TA: HList, HB, TB: HList> IsDisjoint<HCons<HB, TB>> for HCons<HA, TA>
+where
+    // Compare the two heads, then dispatch
+    HA: TypeCmp<HB>,
+    Self: IsDisjointByOrder<HCons<HB, TB>, TypeCompare<HA, HB>>,
+{
+    type Output = <Self as IsDisjointByOrder<HCons<HB, TB>, TypeCompare<HA, HB>>>::Output;
+}
+
+/// Internal dispatch for [`IsDisjoint`] on the ordering of the two heads.
+pub trait IsDisjointByOrder<Rhs: HList, Ord>: HList {
+    /// `B1` if the lists are disjoint, `B0` otherwise.
+    type Output: Bit;
+}
+
+impl<HA, TA: HList, HB, TB: HList> IsDisjointByOrder<HCons<HB, TB>, Less> for HCons<HA, TA>
+where
+    // HA < HB -> drop HA
+    TA: IsDisjoint<HCons<HB, TB>>,
+{
+    type Output = <TA as IsDisjoint<HCons<HB, TB>>>::Output;
+}
+
+impl<HA, TA: HList, HB, TB: HList> IsDisjointByOrder<HCons<HB, TB>, Greater> for HCons<HA, TA>
+where
+    // HA > HB -> drop HB
+    HCons<HA, TA>: IsDisjoint<TB>,
+{
+    type Output = <HCons<HA, TA> as IsDisjoint<TB>>::Output;
+}
+
+impl<HA, TA: HList, HB, TB: HList> IsDisjointByOrder<HCons<HB, TB>, Equal> for HCons<HA, TA> {
+    // HA == HB -> a common element
+    type Output = B0;
+}
+
+/// The number of elements two sorted lists have in common.
+///
+/// Equals the [`Len`](crate::Len) of the [`Intersect`](crate::Intersect)
+/// output, but counts during the walk over both lists instead of building
+/// the intersection.  Prefer it when only the size matters, e.g. to rank
+/// candidate configurations by overlap.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::IntersectLen;
+/// # use typenum::{Unsigned, U1, U2, U3, U4};
+/// assert_eq!(<mk_hlist!(U1, U2, U3) as IntersectLen<mk_hlist!(U2, U3, U4)>>::Output::USIZE, 2);
+/// ```
+pub trait IntersectLen<Other: HList>: HList {
+    /// The number of common elements.
+    type Output: Unsigned;
+}
+
+impl<Other: HList> IntersectLen<Other> for HNil {
+    type Output = U0;
+}
+
+impl<H, T: HList> IntersectLen<HNil> for HCons<H, T> {
+    type Output = U0;
+}
+
+impl<HA, TA: HList, HB, TB: HList> IntersectLen<HCons<HB, TB>> for HCons<HA, TA>
+where
+    // Compare the two heads, then dispatch
+    HA: TypeCmp<HB>,
+    Self: IntersectLenByOrder<HCons<HB, TB>, TypeCompare<HA, HB>>,
+{
+    type Output = <Self as IntersectLenByOrder<HCons<HB, TB>, TypeCompare<HA, HB>>>::Output;
+}
+
+/// Internal dispatch for [`IntersectLen`] on the ordering of the two heads.
+pub trait IntersectLenByOrder<Rhs: HList, Ord>: HList {
+    /// The number of common elements.
+    type Output: Unsigned;
+}
+
+impl<HA, TA: HList, HB, TB: HList> IntersectLenByOrder<HCons<HB, TB>, Less> for HCons<HA, TA>
+where
+    // HA < HB -> drop HA
+    TA: IntersectLen<HCons<HB, TB>>,
+{
+    type Output = <TA as IntersectLen<HCons<HB, TB>>>::Output;
+}
+
+impl<HA, TA: HList, HB, TB: HList> IntersectLenByOrder<HCons<HB, TB>, Greater> for HCons<HA, TA>
+where
+    // HA > HB -> drop HB
+    HCons<HA, TA>: IntersectLen<TB>,
+{
+    type Output = <HCons<HA, TA> as IntersectLen<TB>>::Output;
+}
+
+impl<HA, TA: HList, HB, TB: HList> IntersectLenByOrder<HCons<HB, TB>, Equal> for HCons<HA, TA>
+where
+    // HA == HB -> count it, drop both
+    TA: IntersectLen<TB>,
+    <TA as IntersectLen<TB>>::Output: Add<B1>,
+    Add1<<TA as IntersectLen<TB>>::Output>: Unsigned,
+{
+    type Output = Add1<<TA as IntersectLen<TB>>::Output>;
+}
+
+/// Intersection of two sorted lists that merges each pair of equal elements
+/// with the [`TypeBinaryFn`] `F` instead of keeping the left one.
+///
+/// For equal heads `HA` and `HB` the output holds `F(HA, HB)`.  Both inputs
+/// must be sorted, and so must the output: an `F` that does not keep the
+/// ordering key of its arguments is rejected.  With a function returning its
+/// left argument this is [`Intersect`](crate::Intersect).
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::{IntersectWith, TypeBinaryFn, KV};
+/// # use typenum::{U1, U2, U3, U4, U5};
+/// struct AddCounts;
+///
+/// impl<K, A: core::ops::Add<B>, B> TypeBinaryFn<KV<K, A>, KV<K, B>> for AddCounts {
+///     type Output = KV<K, typenum::Sum<A, B>>;
+/// }
+///
+/// type A = mk_hlist!(KV<U1, U2>, KV<U3, U1>);
+/// type B = mk_hlist!(KV<U3, U4>, KV<U5, U1>);
+/// type Both = <A as IntersectWith<B, AddCounts>>::Output;
+/// assert_type_eq::<Both, mk_hlist!(KV<U3, U5>)>();
+/// ```
+///
+/// Unsorted inputs do not compile:
+///
+/// ```rust,compile_fail
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::{IntersectWith, TypeBinaryFn};
+/// # use typenum::{U1, U5};
+/// struct Left;
+/// impl<A, B> TypeBinaryFn<A, B> for Left {
+///     type Output = A;
+/// }
+/// type Both = <mk_hlist!(U5, U1) as IntersectWith<mk_hlist!(U1, U5), Left>>::Output;
+/// const _: () = assert_type_eq::<Both, mk_hlist!(U5)>();
+/// ```
+///
+/// Nor does a merge that breaks the order:
+///
+/// ```rust,compile_fail
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::{IntersectWith, TypeBinaryFn};
+/// # use typenum::{U0, U1, U2, U9};
+/// struct Invert;
+/// impl TypeBinaryFn<U1, U1> for Invert {
+///     type Output = U9;
+/// }
+/// impl TypeBinaryFn<U2, U2> for Invert {
+///     type Output = U0;
+/// }
+/// type L = mk_hlist!(U1, U2);
+/// type Both = <L as IntersectWith<L, Invert>>::Output;
+/// const _: () = assert_type_eq::<Both, mk_hlist!(U9, U0)>();
+/// ```
+pub trait IntersectWith<Other: SortedHList, F>: SortedHList {
+    /// The merged common elements, in order.
+    type Output: SortedHList;
+}
+
+impl<LA, LB, F> IntersectWith<LB, F> for LA
+where
+    // Only sorted lists may use this impl, and the merge must keep the order
+    LA: SortedHList + IntersectWithUnchecked<LB, F>,
+    LB: SortedHList,
+    <LA as IntersectWithUnchecked<LB, F>>::Output: SortedHList,
+{
+    type Output = <LA as IntersectWithUnchecked<LB, F>>::Output;
+}
+
+/// Unchecked version of [`IntersectWith`]: merges the equal elements of two
+/// lists assumed to be sorted.
+pub trait IntersectWithUnchecked<Other: HList, F>: HList {
+    /// The merged common elements.
+    type Output: HList;
+}
+
+impl<Other: HList, F> IntersectWithUnchecked<Other, F> for HNil {
+    type Output = HNil;
+}
+
+impl<H, T: HList, F> IntersectWithUnchecked<HNil, F> for HCons<H, T> {
+    type Output = HNil;
+}
+
+impl<HA, TA: HList, HB, TB: HList, F> IntersectWithUnchecked<HCons<HB, TB>, F> for HCons<HA, TA>
+where
+    // Compare the two heads, then dispatch
+    HA: TypeCmp<HB>,
+    Self: IntersectWithByOrder<HCons<HB, TB>, F, TypeCompare<HA, HB>>,
+{
+    type Output = <Self as IntersectWithByOrder<HCons<HB, TB>, F, TypeCompare<HA, HB>>>::Output;
+}
+
+/// Internal dispatch for [`IntersectWithUnchecked`] on the ordering of the two
+/// heads.
+pub trait IntersectWithByOrder<Rhs: HList, F, Ord>: HList {
+    /// The merged common elements, in order.
+    type Output: HList;
+}
+
+impl<HA, TA: HList, HB, TB: HList, F> IntersectWithByOrder<HCons<HB, TB>, F, Less> for HCons<HA, TA>
+where
+    // HA < HB -> drop HA
+    TA: IntersectWithUnchecked<HCons<HB, TB>, F>,
+{
+    type Output = <TA as IntersectWithUnchecked<HCons<HB, TB>, F>>::Output;
+}
+
+impl<HA, TA: HList, HB, TB: HList, F> IntersectWithByOrder<HCons<HB, TB>, F, Greater>
+    for HCons<HA, TA>
+where
+    // HA > HB -> drop HB
+    HCons<HA, TA>: IntersectWithUnchecked<TB, F>,
+{
+    type Output = <HCons<HA, TA> as IntersectWithUnchecked<TB, F>>::Output;
+}
+
+impl<HA, TA: HList, HB, TB: HList, F> IntersectWithByOrder<HCons<HB, TB>, F, Equal>
+    for HCons<HA, TA>
+where
+    // HA == HB -> keep F(HA, HB)
+    F: TypeBinaryFn<HA, HB>,
+    TA: IntersectWithUnchecked<TB, F>,
+{
+    type Output =
+        HCons<<F as TypeBinaryFn<HA, HB>>::Output, <TA as IntersectWithUnchecked<TB, F>>::Output>;
+}
+
+/// Proof that `X` occurs in a sorted list.
+///
+/// Holds when some element compares `Equal` to `X`; the search stops with
+/// no impl at the first element greater than `X`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::Member;
+/// # use typenum::{U12, U13, U4};
+/// type Regs = mk_hlist!(U4, U12, U13);
+/// fn read_reg<R: Member<U12>>() {}
+/// read_reg::<Regs>();
+/// ```
+///
+/// A missing element fails to compile:
+///
+/// ```rust,compile_fail
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::Member;
+/// # use typenum::{U12, U13, U4};
+/// type Regs = mk_hlist!(U4, U13);
+/// fn read_reg<R: Member<U12>>() {}
+/// read_reg::<Regs>();
+/// ```
+pub trait Member<X>: HList {}
+
+impl<X, H, T: HList> Member<X> for HCons<H, T>
+where
+    // Compare the head against X, then dispatch
+    H: TypeCmp<X>,
+    Self: MemberByOrder<X, TypeCompare<H, X>>,
+{
+}
+
+/// Internal dispatch for [`Member`] on the ordering of the head against `X`.
+pub trait MemberByOrder<X, Ord>: HList {}
+
+impl<X, H, T> MemberByOrder<X, Less> for HCons<H, T> where
+    // H < X -> look in the tail
+    T: Member<X>
+{
+}
+
+impl<X, H, T: HList> MemberByOrder<X, Equal> for HCons<H, T> {
+    // H == X -> found
+}
+
+/// Proof that every element of `Self` occurs in the sorted list
+/// `Universe`.
+///
+/// Like [`Member`], a bound rather than a `Bit`: a missing element fails to
+/// compile.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::SubsetOf;
+/// # use typenum::{U1, U2, U3};
+/// fn subset<S: SubsetOf<mk_hlist!(U1, U2, U3)>>() {}
+/// subset::<mk_hlist!(U1, U3)>();
+/// ```
+///
+/// ```rust,compile_fail
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::SubsetOf;
+/// # use typenum::{U1, U2, U3, U4};
+/// fn subset<S: SubsetOf<mk_hlist!(U1, U2, U3)>>() {}
+/// subset::<mk_hlist!(U1, U4)>();
+/// ```
+pub trait SubsetOf<Universe: HList>: HList {}
+
+impl<Universe: HList> SubsetOf<Universe> for HNil {}
+
+impl<Universe, H, T> SubsetOf<Universe> for HCons<H, T>
+where
+    // H is in the universe, and so is the rest
+    Universe: Member<H>,
+    T: SubsetOf<Universe>,
+{
+}
+
+/// The elements of `Universe` not in `Self`, in sorted order.
+///
+/// Both lists must be sorted and `Self` a [`SubsetOf`] the universe; the
+/// result is the [`Difference`] of the universe and `Self`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::Complement;
+/// # use typenum::{U0, U1, U2, U3, U4};
+/// type Lines = mk_hlist!(U0, U1, U2, U3, U4);
+/// type Free = <mk_hlist!(U1, U3) as Complement<Lines>>::Output;
+/// assert_type_eq::<Free, mk_hlist!(U0, U2, U4)>();
+/// ```
+///
+/// A list with elements outside the universe has no complement:
+///
+/// ```rust,compile_fail
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::Complement;
+/// # use typenum::{U0, U1, U2, U3, U7};
+/// type Lines = mk_hlist!(U0, U1, U2, U3);
+/// fn free<S: Complement<Lines>>() {}
+/// free::<mk_hlist!(U1, U7)>();
+/// ```
+pub trait Complement<Universe: HList>: SubsetOf<Universe> {
+    /// The sorted elements of `Universe` missing from `Self`.
+    type Output: SortedHList;
+}
+
+impl<Universe, S> Complement<Universe> for S
+where
+    S: SortedHList + SubsetOf<Universe>,
+    Universe: Difference<S>,
+{
+    type Output = <Universe as Difference<S>>::Output;
+}
+
+/// Whether every pair of sets in an HList of sorted sets is disjoint.
+///
+/// Each set is checked against every later set, so this is quadratic in the
+/// number of sets.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::PairwiseDisjoint;
+/// # use typenum::{Bit, U1, U2, U3, U4};
+/// type Partition = mk_hlist!(mk_hlist!(U1), mk_hlist!(U2, U4), mk_hlist!(U3));
+/// assert!(<Partition as PairwiseDisjoint>::Output::BOOL);
+/// ```
+pub trait PairwiseDisjoint: HList {
+    /// `B1` if all pairs of sets are disjoint, `B0` otherwise.
+    type Output: Bit;
+}
+
+impl PairwiseDisjoint for HNil {
+    type Output = B1;
+}
+
+impl<S, Rest> PairwiseDisjoint for HCons<S, Rest>
+where
+    // the head set is disjoint from all later sets, and so are those
+    S: DisjointFromAll<Rest>,
+    Rest: PairwiseDisjoint,
+    <S as DisjointFromAll<Rest>>::Output: BitAnd<<Rest as PairwiseDisjoint>::Output>,
+    And<<S as DisjointFromAll<Rest>>::Output, <Rest as PairwiseDisjoint>::Output>: Bit,
+{
+    type Output = And<<S as DisjointFromAll<Rest>>::Output, <Rest as PairwiseDisjoint>::Output>;
+}
+
+/// Internal helper for [`PairwiseDisjoint`]: whether `Self` is disjoint from
+/// every set in `Sets`.
+pub trait DisjointFromAll<Sets: HList>: HList {
+    /// `B1` if `Self` is disjoint from all of `Sets`, `B0` otherwise.
+    type Output: Bit;
+}
+
+impl<S: HList> DisjointFromAll<HNil> for S {
+    type Output = B1;
+}
+
+impl<S, H, T> DisjointFromAll<HCons<H, T>> for S
+where
+    S: IsDisjoint<H> + DisjointFromAll<T>,
+    H: HList,
+    T: HList,
+    <S as IsDisjoint<H>>::Output: BitAnd<<S as DisjointFromAll<T>>::Output>,
+    And<<S as IsDisjoint<H>>::Output, <S as DisjointFromAll<T>>::Output>: Bit,
+{
+    type Output = And<<S as IsDisjoint<H>>::Output, <S as DisjointFromAll<T>>::Output>;
+}
+
+/// Union of two sorted lists.
+///
+/// Elements present in both lists appear once in the output, taken from the
+/// left list.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use typenum::{U1, U2, U3, U4};
+/// type All = <mk_hlist!(U1, U2, U3) as Union<mk_hlist!(U2, U4)>>::Output;
+/// assert_type_eq::<All, mk_hlist!(U1, U2, U3, U4)>();
+/// ```
+///
+/// Unsorted inputs do not compile:
+///
+/// ```rust,compile_fail
+/// # use sorted_hlist::prelude::*;
+/// # use typenum::{U1, U2, U3};
+/// type All = <mk_hlist!(U3, U1) as Union<mk_hlist!(U2)>>::Output;
+/// const _: () = assert_type_eq::<All, mk_hlist!(U2, U3, U1)>();
+/// ```
+pub trait Union<Other: SortedHList>: SortedHList {
+    /// The sorted union of `Self` and `Other`.
+    type Output: SortedHList;
+}
+
+impl<LA, LB> Union<LB> for LA
+where
+    // Only sorted lists may use this impl
+    LA: SortedHList + UnionUnchecked<LB>,
+    LB: SortedHList,
+    <LA as UnionUnchecked<LB>>::Output: SortedHList,
+{
+    type Output = <LA as UnionUnchecked<LB>>::Output;
+}
+
+/// Unchecked version of [`Union`]: merges two lists assumed to be sorted.
+pub trait UnionUnchecked<Other: HList>: HList {
+    /// The union of `Self` and `Other`.
+    type Output: HList;
+}
+
+impl<Other: HList> UnionUnchecked<Other> for HNil {
+    type Output = Other;
+}
+
+impl<H, T: HList> UnionUnchecked<HNil> for HCons<H, T> {
+    type Output = Self;
+}
+
+impl<HA, TA: HList, HB, TB: HList> UnionUnchecked<HCons<HB, TB>> for HCons<HA, TA>
+where
+    // Compare the two heads, then dispatch
+    HA: TypeCmp<HB>,
+    Self: UnionByOrder<HCons<HB, TB>, TypeCompare<HA, HB>>,
+{
+    type Output = <Self as UnionByOrder<HCons<HB, TB>, TypeCompare<HA, HB>>>::Output;
+}
+
+/// Union of any number of sorted HList types, left to right.
+///
+/// `union_all!(A, B, C)` expands to
+/// `<<A as Union<B>>::Output as Union<C>>::Output`.  At least two arguments
+/// are required.  For an HList *of* sets see [`UnionAll`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::union_all;
+/// # use typenum::{U1, U2, U3};
+/// type L = union_all!(mk_hlist!(U1), mk_hlist!(U3), mk_hlist!(U1, U2));
+/// // L = mk_hlist!(U1, U2, U3)
+/// ```
+///
+/// ```rust,compile_fail
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::union_all;
+/// # use typenum::U1;
+/// type L = union_all!(mk_hlist!(U1));
+/// ```
+#[macro_export]
+macro_rules! union_all {
+    ($($only:ty)? $(,)?) => {
+        compile_error!("union_all! requires at least two arguments")
+    };
+    ($first:ty, $second:ty $(,)?) => {
+        <$first as $crate::Union<$second>>::Output
+    };
+    ($first:ty, $second:ty, $($rest:ty),+ $(,)?) => {
+        $crate::union_all!(<$first as $crate::Union<$second>>::Output, $($rest),+)
+    };
+}
+
+/// Internal dispatch for [`UnionUnchecked`] on the ordering of the two heads.
+pub trait UnionByOrder<Rhs: HList, Ord>: HList {
+    /// The sorted union.
+    type Output: HList;
+}
+
+impl<HA, TA: HList, HB, TB: HList> UnionByOrder<HCons<HB, TB>, Less> for HCons<HA, TA>
+where
+    // HA < HB -> take HA
+    TA: UnionUnchecked<HCons<HB, TB>>,
+{
+    type Output = HCons<HA, <TA as UnionUnchecked<HCons<HB, TB>>>::Output>;
+}
+
+impl<HA, TA: HList, HB, TB: HList> UnionByOrder<HCons<HB, TB>, Greater> for HCons<HA, TA>
+where
+    // HA > HB -> take HB
+    HCons<HA, TA>: UnionUnchecked<TB>,
+{
+    type Output = HCons<HB, <HCons<HA, TA> as UnionUnchecked<TB>>::Output>;
+}
+
+impl<HA, TA: HList, HB, TB: HList> UnionByOrder<HCons<HB, TB>, Equal> for HCons<HA, TA>
+where
+    // HA == HB -> take HA once, drop HB
+    TA: UnionUnchecked<TB>,
+{
+    type Output = HCons<HA, <TA as UnionUnchecked<TB>>::Output>;
+}
+
+/// Difference of two sorted lists: the elements of `Self` not present in
+/// `Other`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use typenum::{U1, U2, U3, U4};
+/// type Rest = <mk_hlist!(U1, U2, U3) as Difference<mk_hlist!(U2, U4)>>::Output;
+/// assert_type_eq::<Rest, mk_hlist!(U1, U3)>();
+/// ```
+///
+/// Unsorted inputs do not compile:
+///
+/// ```rust,compile_fail
+/// # use sorted_hlist::prelude::*;
+/// # use typenum::{U1, U2, U3};
+/// type Rest = <mk_hlist!(U1, U2) as Difference<mk_hlist!(U3, U1)>>::Output;
+/// const _: () = assert_type_eq::<Rest, mk_hlist!(U1, U2)>();
+/// ```
+pub trait Difference<Other: SortedHList>: SortedHList {
+    /// The sorted list of elements of `Self` missing from `Other`.
+    type Output: SortedHList;
+}
+
+impl<LA, LB> Difference<LB> for LA
+where
+    // Only sorted lists may use this impl
+    LA: SortedHList + DifferenceUnchecked<LB>,
+    LB: SortedHList,
+    <LA as DifferenceUnchecked<LB>>::Output: SortedHList,
+{
+    type Output = <LA as DifferenceUnchecked<LB>>::Output;
+}
+
+/// Unchecked version of [`Difference`]: the elements of `Self` missing from
+/// `Other`, both assumed to be sorted.
+pub trait DifferenceUnchecked<Other: HList>: HList {
+    /// The elements of `Self` missing from `Other`.
+    type Output: HList;
+}
+
+impl<Other: HList> DifferenceUnchecked<Other> for HNil {
+    type Output = HNil;
+}
+
+impl<H, T: HList> DifferenceUnchecked<HNil> for HCons<H, T> {
+    type Output = Self;
+}
+
+impl<HA, TA: HList, HB, TB: HList> DifferenceUnchecked<HCons<HB, TB>> for HCons<HA, TA>
+where
+    // Compare the two heads, then dispatch
+    HA: TypeCmp<HB>,
+    Self: DifferenceByOrder<HCons<HB, TB>, TypeCompare<HA, HB>>,
+{
+    type Output = <Self as DifferenceByOrder<HCons<HB, TB>, TypeCompare<HA, HB>>>::Output;
+}
+
+/// Internal dispatch for [`DifferenceUnchecked`] on the ordering of the two heads.
+pub trait DifferenceByOrder<Rhs: HList, Ord>: HList {
+    /// The sorted difference.
+    type Output: HList;
+}
+
+impl<HA, TA: HList, HB, TB: HList> DifferenceByOrder<HCons<HB, TB>, Less> for HCons<HA, TA>
+where
+    // HA < HB -> HA is not in RHS, keep it
+    TA: DifferenceUnchecked<HCons<HB, TB>>,
+{
+    type Output = HCons<HA, <TA as DifferenceUnchecked<HCons<HB, TB>>>::Output>;
+}
+
+impl<HA, TA: HList, HB, TB: HList> DifferenceByOrder<HCons<HB, TB>, Greater> for HCons<HA, TA>
+where
+    // HA > HB -> drop HB
+    HCons<HA, TA>: DifferenceUnchecked<TB>,
+{
+    type Output = <HCons<HA, TA> as DifferenceUnchecked<TB>>::Output;
+}
+
+impl<HA, TA: HList, HB, TB: HList> DifferenceByOrder<HCons<HB, TB>, Equal> for HCons<HA, TA>
+where
+    // HA == HB -> drop HA
+    TA: DifferenceUnchecked<TB>,
+{
+    type Output = <TA as DifferenceUnchecked<TB>>::Output;
+}
+
+/// Union of every set in an HList of sorted sets.
+pub trait UnionAll: HList {
+    /// The sorted union of all sets.
+    type Output: SortedHList;
+}
+
+impl UnionAll for HNil {
+    type Output = HNil;
+}
+
+impl<S, Rest> UnionAll for HCons<S, Rest>
+where
+    Rest: UnionAll,
+    S: Union<<Rest as UnionAll>::Output>,
+{
+    type Output = <S as Union<<Rest as UnionAll>::Output>>::Output;
+}
+
+/// The elements present in at least `K` of an HList of sorted sets, as a
+/// sorted set.
+///
+/// Each set casts at most one vote per element, however often it repeats
+/// it.  For duplicate-free sets, `K` equal to the number of sets gives the
+/// intersection of all of them, and `K = U1` gives [`UnionAll`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::VotingIntersect;
+/// # use typenum::{U1, U2, U3, U4};
+/// type Backends = mk_hlist!(
+///     mk_hlist!(U1, U2, U3),
+///     mk_hlist!(U2, U3),
+///     mk_hlist!(U3, U4)
+/// );
+/// assert_type_eq::<<Backends as VotingIntersect<U2>>::Output, mk_hlist!(U2, U3)>();
+/// ```
+pub trait VotingIntersect<K: Unsigned>: HList {
+    /// The sorted elements with at least `K` votes.
+    type Output: HList;
+}
+
+impl<K: Unsigned, Sets> VotingIntersect<K> for Sets
+where
+    // merge the deduplicated sets, count each run, keep runs of K or more
+    Sets: MergeDistinct,
+    <Sets as MergeDistinct>::Output: RunLengthEncode,
+    <<Sets as MergeDistinct>::Output as RunLengthEncode>::Output: KeepVotes<K>,
+{
+    type Output =
+        <<<Sets as MergeDistinct>::Output as RunLengthEncode>::Output as KeepVotes<K>>::Output;
+}
+
+/// Internal helper for [`VotingIntersect`]: every set of an HList of
+/// sorted sets deduplicated, merged into one sorted list.
+pub trait MergeDistinct: HList {
+    /// The merged elements, each repeated once per set containing it.
+    type Output: SortedHList;
+}
+
+impl MergeDistinct for HNil {
+    type Output = HNil;
+}
+
+impl<S, Rest> MergeDistinct for HCons<S, Rest>
+where
+    // the keys of a run-length encoding are the distinct elements
+    S: RunLengthEncode,
+    <S as RunLengthEncode>::Output: Keys,
+    Rest: MergeDistinct,
+    <<S as RunLengthEncode>::Output as Keys>::Output: MergeSorted<<Rest as MergeDistinct>::Output>,
+{
+    type Output = <<<S as RunLengthEncode>::Output as Keys>::Output as MergeSorted<
+        <Rest as MergeDistinct>::Output,
+    >>::Output;
+}
+
+/// Internal helper for [`VotingIntersect`]: the keys of the
+/// `KV<Element, Count>` entries whose count is at least `K`.
+pub trait KeepVotes<K>: HList {
+    /// The elements with at least `K` votes.
+    type Output: HList;
+}
+
+impl<K> KeepVotes<K> for HNil {
+    type Output = HNil;
+}
+
+impl<K, E, C, T: HList> KeepVotes<K> for HCons<KV<E, C>, T>
+where
+    // Compare the count against K, then dispatch
+    C: IsGreaterOrEqual<K>,
+    Self: KeepVotesByBit<K, GrEq<C, K>>,
+{
+    type Output = <Self as KeepVotesByBit<K, GrEq<C, K>>>::Output;
+}
+
+/// Internal dispatch for [`KeepVotes`] on whether the first count reaches
+/// `K`.
+pub trait KeepVotesByBit<K, B: Bit>: HList {
+    /// The elements with at least `K` votes.
+    type Output: HList;
+}
+
+impl<K, E, C, T: KeepVotes<K>> KeepVotesByBit<K, B1> for HCons<KV<E, C>, T> {
+    // enough votes -> keep E
+    type Output = HCons<E, <T as KeepVotes<K>>::Output>;
+}
+
+impl<K, E, C, T: KeepVotes<K>> KeepVotesByBit<K, B0> for HCons<KV<E, C>, T> {
+    // too few votes -> drop E
+    type Output = <T as KeepVotes<K>>::Output;
+}
+
+/// Whether two lists are equal element by element: they have the same length
+/// and the keys of corresponding elements compare `Equal`.
+pub trait HListEq<Other: HList>: HList {
+    /// `B1` if the lists are equal, `B0` otherwise.
+    type Output: Bit;
+}
+
+impl HListEq<HNil> for HNil {
+    type Output = B1;
+}
+
+impl<H, T: HList> HListEq<HCons<H, T>> for HNil {
+    type Output = B0;
+}
+
+impl<H, T: HList> HListEq<HNil> for HCons<H, T> {
+    type Output = B0;
+}
+
+impl<HA, TA: HList, HB, TB: HList> HListEq<HCons<HB, TB>> for HCons<HA, TA>
+where
+    // Compare the two heads, then dispatch
+    HA: TypeCmp<HB>,
+    Self: HListEqByOrder<HCons<HB, TB>, TypeCompare<HA, HB>>,
+{
+    type Output = <Self as HListEqByOrder<HCons<HB, TB>, TypeCompare<HA, HB>>>::Output;
+}
+
+/// Internal dispatch for [`HListEq`] on the ordering of the two heads.
+pub trait HListEqByOrder<Rhs: HList, Ord>: HList {
+    /// `B1` if the lists are equal, `B0` otherwise.
+    type Output: Bit;
+}
+
+impl<HA, TA: HList, HB, TB: HList> HListEqByOrder<HCons<HB, TB>, Less> for HCons<HA, TA> {
+    type Output = B0;
+}
+
+impl<HA, TA: HList, HB, TB: HList> HListEqByOrder<HCons<HB, TB>, Greater> for HCons<HA, TA> {
+    type Output = B0;
+}
+
+impl<HA, TA: HList, HB, TB: HList> HListEqByOrder<HCons<HB, TB>, Equal> for HCons<HA, TA>
+where
+    // heads match -> compare the tails
+    TA: HListEq<TB>,
+{
+    type Output = <TA as HListEq<TB>>::Output;
+}
+
+/// Whether two lists contain the same distinct elements, regardless of
+/// order and of how often each occurs.
+///
+/// Both lists are sorted and deduplicated, then compared with [`HListEq`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::SetEq;
+/// # use typenum::{Bit, U1, U2, U3};
+/// assert!(<mk_hlist!(U2, U1, U2) as SetEq<mk_hlist!(U1, U2)>>::Output::BOOL);
+/// assert!(!<mk_hlist!(U1, U2) as SetEq<mk_hlist!(U1, U3)>>::Output::BOOL);
+/// ```
+pub trait SetEq<Other: HList>: HList {
+    /// `B1` if the lists have the same distinct elements, `B0` otherwise.
+    type Output: Bit;
+}
+
+impl<A, B> SetEq<B> for A
+where
+    // the keys of a run-length encoding are the distinct elements
+    A: Sort,
+    B: Sort,
+    <A as Sort>::Output: RunLengthEncode,
+    <B as Sort>::Output: RunLengthEncode,
+    <<A as Sort>::Output as RunLengthEncode>::Output: Keys,
+    <<B as Sort>::Output as RunLengthEncode>::Output: Keys,
+    <<<A as Sort>::Output as RunLengthEncode>::Output as Keys>::Output:
+        HListEq<<<<B as Sort>::Output as RunLengthEncode>::Output as Keys>::Output>,
+{
+    type Output = <<<<A as Sort>::Output as RunLengthEncode>::Output as Keys>::Output as HListEq<
+        <<<B as Sort>::Output as RunLengthEncode>::Output as Keys>::Output,
+    >>::Output;
+}
+
+/// Whether two lists contain the same elements the same number of times,
+/// regardless of order.
+///
+/// Both lists are sorted, then compared with [`HListEq`]; for sorted inputs
+/// this is `HListEq` itself.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::MultisetEq;
+/// # use typenum::{Bit, U1, U2};
+/// assert!(<mk_hlist!(U2, U1, U2) as MultisetEq<mk_hlist!(U1, U2, U2)>>::Output::BOOL);
+/// assert!(!<mk_hlist!(U1, U2, U2) as MultisetEq<mk_hlist!(U1, U2)>>::Output::BOOL);
+/// ```
+pub trait MultisetEq<Other: HList>: HList {
+    /// `B1` if every element occurs equally often in both, `B0` otherwise.
+    type Output: Bit;
+}
+
+impl<A, B> MultisetEq<B> for A
+where
+    A: Sort,
+    B: Sort,
+    <A as Sort>::Output: HListEq<<B as Sort>::Output>,
+{
+    type Output = <<A as Sort>::Output as HListEq<<B as Sort>::Output>>::Output;
+}
+
+/// Whether the union of an HList of sorted sets is exactly `Universe`.
+///
+/// `Universe` is expected to be duplicate-free.  Together with
+/// [`PairwiseDisjoint`] this asserts that the sets partition the universe.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::{PairwiseDisjoint, TotalCover};
+/// # use typenum::{Bit, U1, U2, U3, U4};
+/// type Universe = mk_hlist!(U1, U2, U3, U4);
+/// type Parts = mk_hlist!(mk_hlist!(U1, U3), mk_hlist!(U2), mk_hlist!(U4));
+/// const _: () = assert!(<Parts as TotalCover<Universe>>::Output::BOOL);
+/// const _: () = assert!(<Parts as PairwiseDisjoint>::Output::BOOL);
+/// ```
+pub trait TotalCover<Universe: HList>: HList {
+    /// `B1` if the union of the sets equals `Universe`, `B0` otherwise.
+    type Output: Bit;
+}
+
+impl<Sets, Universe> TotalCover<Universe> for Sets
+where
+    Sets: UnionAll,
+    Universe: HList,
+    <Sets as UnionAll>::Output: HListEq<Universe>,
+{
+    type Output = <<Sets as UnionAll>::Output as HListEq<Universe>>::Output;
+}
+
+/// Whether a sorted list has no repeated elements.
+///
+/// A repeat yields `B0` instead of a compile error.  Adjacent pairs are
+/// compared, so the input must be sorted; a descending pair has no impl.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::AllDistinct;
+/// # use typenum::{Bit, U1, U2, U3};
+/// assert!(<mk_hlist!(U1, U2, U3) as AllDistinct>::Output::BOOL);
+/// assert!(!<mk_hlist!(U1, U2, U2) as AllDistinct>::Output::BOOL);
+/// ```
+pub trait AllDistinct: HList {
+    /// `B1` if no element repeats, `B0` otherwise.
+    type Output: Bit;
+}
+
+impl AllDistinct for HNil {
+    type Output = B1;
+}
+
+impl<H> AllDistinct for HCons<H, HNil> {
+    type Output = B1;
+}
+
+impl<H, N, T: HList> AllDistinct for HCons<H, HCons<N, T>>
+where
+    // Compare the first two elements, then dispatch
+    H: TypeCmp<N>,
+    Self: AllDistinctByOrder<TypeCompare<H, N>>,
+{
+    type Output = <Self as AllDistinctByOrder<TypeCompare<H, N>>>::Output;
+}
+
+/// Internal dispatch for [`AllDistinct`] on the ordering of the first two
+/// elements.
+pub trait AllDistinctByOrder<Ord>: HList {
+    /// `B1` if no element repeats, `B0` otherwise.
+    type Output: Bit;
+}
+
+impl<H, N, T: HList> AllDistinctByOrder<Less> for HCons<H, HCons<N, T>>
+where
+    // H < N -> check from N on
+    HCons<N, T>: AllDistinct,
+{
+    type Output = <HCons<N, T> as AllDistinct>::Output;
+}
+
+impl<H, N, T: HList> AllDistinctByOrder<Equal> for HCons<H, HCons<N, T>> {
+    // H == N -> a repeat
+    type Output = B0;
+}
+
+/// The elements occurring more than once in a sorted list, each listed
+/// once, in ascending order.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::Duplicates;
+/// # use typenum::{U1, U2, U3, U4};
+/// type L = <mk_hlist!(U1, U2, U2, U2, U3, U4, U4) as Duplicates>::Output;
+/// assert_type_eq::<L, mk_hlist!(U2, U4)>();
+/// ```
+pub trait Duplicates: HList {
+    /// The repeated elements, ascending and without repeats.
+    type Output: HList;
+}
+
+impl Duplicates for HNil {
+    type Output = HNil;
+}
+
+impl<H> Duplicates for HCons<H, HNil> {
+    type Output = HNil;
+}
+
+impl<H, N, T: HList> Duplicates for HCons<H, HCons<N, T>>
+where
+    // Compare the first two elements, then dispatch
+    H: TypeCmp<N>,
+    Self: DuplicatesByOrder<TypeCompare<H, N>>,
+{
+    type Output = <Self as DuplicatesByOrder<TypeCompare<H, N>>>::Output;
+}
+
+/// Internal dispatch for [`Duplicates`] on the ordering of the first two
+/// elements.
+pub trait DuplicatesByOrder<Ord>: HList {
+    /// The repeated elements, ascending and without repeats.
+    type Output: HList;
+}
+
+impl<H, N, T: HList> DuplicatesByOrder<Less> for HCons<H, HCons<N, T>>
+where
+    // H < N -> H occurs once
+    HCons<N, T>: Duplicates,
+{
+    type Output = <HCons<N, T> as Duplicates>::Output;
+}
+
+impl<H, N, T: HList> DuplicatesByOrder<Equal> for HCons<H, HCons<N, T>>
+where
+    // H == N -> keep H, then skip the rest of its run
+    T: DropRun<H>,
+    <T as DropRun<H>>::Output: Duplicates,
+{
+    type Output = HCons<H, <<T as DropRun<H>>::Output as Duplicates>::Output>;
+}
+
+/// A sorted list with each run of equal elements reduced to one element.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::Deduplicate;
+/// # use typenum::{U1, U2, U3};
+/// type L = <mk_hlist!(U1, U1, U2, U3, U3) as Deduplicate>::Output;
+/// assert_type_eq::<L, mk_hlist!(U1, U2, U3)>();
+/// ```
+pub trait Deduplicate: HList {
+    /// The distinct elements, in order.
+    type Output: HList;
+}
+
+impl Deduplicate for HNil {
+    type Output = HNil;
+}
+
+impl<H, T> Deduplicate for HCons<H, T>
+where
+    // keep H, skip the rest of its run, deduplicate what follows
+    T: DropRun<H>,
+    <T as DropRun<H>>::Output: Deduplicate,
+{
+    type Output = HCons<H, <<T as DropRun<H>>::Output as Deduplicate>::Output>;
+}
+
+/// Internal helper for [`Duplicates`] and [`Deduplicate`]: `Self` without
+/// its leading elements
+/// equal to `X`.
+pub trait DropRun<X>: HList {
+    /// The list from the first element not equal to `X` on.
+    type Output: HList;
+}
+
+impl<X> DropRun<X> for HNil {
+    type Output = HNil;
+}
+
+impl<X, H, T: HList> DropRun<X> for HCons<H, T>
+where
+    // Compare the head against X, then dispatch
+    H: TypeCmp<X>,
+    Self: DropRunByOrder<X, TypeCompare<H, X>>,
+{
+    type Output = <Self as DropRunByOrder<X, TypeCompare<H, X>>>::Output;
+}
+
+/// Internal dispatch for [`DropRun`] on the ordering of the head against
+/// `X`.
+pub trait DropRunByOrder<X, Ord>: HList {
+    /// The list from the first element not equal to `X` on.
+    type Output: HList;
+}
+
+impl<X, H, T: DropRun<X>> DropRunByOrder<X, Equal> for HCons<H, T> {
+    // H == X -> drop it
+    type Output = <T as DropRun<X>>::Output;
+}
+
+impl<X, H, T: HList> DropRunByOrder<X, Greater> for HCons<H, T> {
+    // H > X -> the run is over
+    type Output = Self;
+}
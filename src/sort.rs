@@ -0,0 +1,339 @@
+//! Sorting arbitrary HLists into [`SortedHList`](crate::SortedHList)s.
+
+use crate::{Deduplicate, HCons, HList, HNil, SortedHList, TypeCmp, TypeCompare};
+use typenum::{Equal, Greater, Less};
+
+/// Insert `X` into a sorted list at its ordered position.
+///
+/// `X` is placed before any elements comparing `Equal` to it.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::Insert;
+/// # use typenum::{U1, U2, U3};
+/// type L = <mk_hlist!(U1, U3) as Insert<U2>>::Output;
+/// // L = mk_hlist!(U1, U2, U3)
+/// ```
+pub trait Insert<X>: HList {
+    /// `Self` with `X` inserted.
+    type Output: HList;
+}
+
+impl<X> Insert<X> for HNil {
+    type Output = HCons<X, HNil>;
+}
+
+impl<H, T: HList, X> Insert<X> for HCons<H, T>
+where
+    // Compare the head against X, then dispatch
+    H: TypeCmp<X>,
+    Self: InsertByOrder<X, TypeCompare<H, X>>,
+{
+    type Output = <Self as InsertByOrder<X, TypeCompare<H, X>>>::Output;
+}
+
+/// Internal dispatch for [`Insert`] on the ordering of the head against the
+/// inserted element.
+pub trait InsertByOrder<X, Ord>: HList {
+    /// `Self` with `X` inserted.
+    type Output: HList;
+}
+
+impl<H, T, X> InsertByOrder<X, Less> for HCons<H, T>
+where
+    // H < X -> X goes further down
+    T: Insert<X>,
+{
+    type Output = HCons<H, <T as Insert<X>>::Output>;
+}
+
+impl<H, T: HList, X> InsertByOrder<X, Equal> for HCons<H, T> {
+    // H == X -> X goes first
+    type Output = HCons<X, Self>;
+}
+
+impl<H, T: HList, X> InsertByOrder<X, Greater> for HCons<H, T> {
+    // H > X -> X goes first
+    type Output = HCons<X, Self>;
+}
+
+/// Insert `X` into a sorted set at its ordered position, failing to compile
+/// if an element comparing `Equal` to `X` is already present.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::InsertUnique;
+/// # use typenum::{U1, U2, U3};
+/// type L = <mk_hlist!(U1, U3) as InsertUnique<U2>>::Output;
+/// // L = mk_hlist!(U1, U2, U3)
+/// ```
+///
+/// Inserting an element that is already present fails:
+///
+/// ```rust,compile_fail
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::InsertUnique;
+/// # use typenum::{U1, U3};
+/// fn insert<L: InsertUnique<U3>>() {}
+/// insert::<mk_hlist!(U1, U3)>();
+/// ```
+pub trait InsertUnique<X>: HList {
+    /// `Self` with `X` inserted.
+    type Output: HList;
+}
+
+impl<X> InsertUnique<X> for HNil {
+    type Output = HCons<X, HNil>;
+}
+
+impl<H, T: HList, X> InsertUnique<X> for HCons<H, T>
+where
+    // Compare the head against X, then dispatch
+    H: TypeCmp<X>,
+    Self: InsertUniqueByOrder<X, TypeCompare<H, X>>,
+{
+    type Output = <Self as InsertUniqueByOrder<X, TypeCompare<H, X>>>::Output;
+}
+
+/// Internal dispatch for [`InsertUnique`] on the ordering of the head against
+/// the inserted element.  There is no `Equal` impl.
+pub trait InsertUniqueByOrder<X, Ord>: HList {
+    /// `Self` with `X` inserted.
+    type Output: HList;
+}
+
+impl<H, T, X> InsertUniqueByOrder<X, Less> for HCons<H, T>
+where
+    // H < X -> X goes further down
+    T: InsertUnique<X>,
+{
+    type Output = HCons<H, <T as InsertUnique<X>>::Output>;
+}
+
+impl<H, T: HList, X> InsertUniqueByOrder<X, Greater> for HCons<H, T> {
+    // H > X -> X goes first
+    type Output = HCons<X, Self>;
+}
+
+/// Remove the first element comparing `Equal` to `X` from a sorted list.
+///
+/// Fails to compile if there is no such element.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::Remove;
+/// # use typenum::{U1, U2, U3};
+/// type L = <mk_hlist!(U1, U2, U3) as Remove<U2>>::Output;
+/// // L = mk_hlist!(U1, U3)
+/// ```
+pub trait Remove<X>: HList {
+    /// `Self` without the first element equal to `X`.
+    type Output: HList;
+}
+
+impl<H, T: HList, X> Remove<X> for HCons<H, T>
+where
+    // Compare the head against X, then dispatch
+    H: TypeCmp<X>,
+    Self: RemoveByOrder<X, TypeCompare<H, X>>,
+{
+    type Output = <Self as RemoveByOrder<X, TypeCompare<H, X>>>::Output;
+}
+
+/// Internal dispatch for [`Remove`] on the ordering of the head against the
+/// removed element.
+pub trait RemoveByOrder<X, Ord>: HList {
+    /// `Self` without the first element equal to `X`.
+    type Output: HList;
+}
+
+impl<H, T, X> RemoveByOrder<X, Less> for HCons<H, T>
+where
+    // H < X -> X is further down
+    T: Remove<X>,
+{
+    type Output = HCons<H, <T as Remove<X>>::Output>;
+}
+
+impl<H, T: HList, X> RemoveByOrder<X, Equal> for HCons<H, T> {
+    // H == X -> drop H
+    type Output = T;
+}
+
+/// Replace the first element comparing `Equal` to `Old` with `New`, at
+/// `New`'s sorted position: [`Remove`] followed by [`Insert`].
+///
+/// Fails to compile if `Old` is absent.  Replacing an element with itself
+/// leaves the list unchanged.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::ReplaceElement;
+/// # use typenum::{U1, U2, U3, U4};
+/// type L = <mk_hlist!(U1, U2, U3) as ReplaceElement<U1, U4>>::Output;
+/// // L = mk_hlist!(U2, U3, U4)
+/// ```
+///
+/// ```rust,compile_fail
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::ReplaceElement;
+/// # use typenum::{U1, U2, U3, U4, U5};
+/// fn replace<L: ReplaceElement<U5, U4>>() {}
+/// replace::<mk_hlist!(U1, U2, U3)>();
+/// ```
+pub trait ReplaceElement<Old, New>: HList {
+    /// `Self` with `Old` replaced by `New`.
+    type Output: HList;
+}
+
+impl<L, Old, New> ReplaceElement<Old, New> for L
+where
+    L: Remove<Old>,
+    <L as Remove<Old>>::Output: Insert<New>,
+{
+    type Output = <<L as Remove<Old>>::Output as Insert<New>>::Output;
+}
+
+/// Sort an HList into non-decreasing order (a stable insertion sort).
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::Sort;
+/// # use typenum::{U1, U2, U3};
+/// type L = <mk_hlist!(U3, U1, U2) as Sort>::Output;
+/// // L = mk_hlist!(U1, U2, U3)
+/// ```
+pub trait Sort: HList {
+    /// The elements of `Self` in sorted order.
+    type Output: HList;
+}
+
+impl Sort for HNil {
+    type Output = HNil;
+}
+
+impl<H, T> Sort for HCons<H, T>
+where
+    // sort the tail, then insert the head
+    T: Sort,
+    <T as Sort>::Output: Insert<H>,
+{
+    type Output = <<T as Sort>::Output as Insert<H>>::Output;
+}
+
+/// The canonical set form of any list: sorted, with duplicates removed.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::Normalize;
+/// # use typenum::{U1, U2, U3};
+/// assert_type_eq::<Normalize<mk_hlist!(U3, U1, U3, U2)>, mk_hlist!(U1, U2, U3)>();
+/// ```
+pub type Normalize<L> = <<L as Sort>::Output as Deduplicate>::Output;
+
+/// Merge two sorted lists into one sorted list, keeping every element of
+/// both: the merge step of merge sort.
+///
+/// Unlike [`Union`](crate::Union), which keeps one of two equal elements,
+/// `MergeSorted` keeps both, so the output length is always the sum of the
+/// input lengths.  For equal elements, the one from `Self` comes first.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::MergeSorted;
+/// # use typenum::{U1, U2, U3, U4};
+/// type L = <mk_hlist!(U1, U2, U4) as MergeSorted<mk_hlist!(U2, U3)>>::Output;
+/// assert_type_eq::<L, mk_hlist!(U1, U2, U2, U3, U4)>();
+/// ```
+///
+/// Unsorted inputs do not compile:
+///
+/// ```rust,compile_fail
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::MergeSorted;
+/// # use typenum::{U1, U2, U3};
+/// type L = <mk_hlist!(U2) as MergeSorted<mk_hlist!(U3, U1)>>::Output;
+/// const _: () = assert_type_eq::<L, mk_hlist!(U2, U3, U1)>();
+/// ```
+pub trait MergeSorted<Other: SortedHList>: SortedHList {
+    /// The sorted elements of both lists.
+    type Output: SortedHList;
+}
+
+impl<LA, LB> MergeSorted<LB> for LA
+where
+    // Only sorted lists may use this impl
+    LA: SortedHList + MergeSortedUnchecked<LB>,
+    LB: SortedHList,
+    <LA as MergeSortedUnchecked<LB>>::Output: SortedHList,
+{
+    type Output = <LA as MergeSortedUnchecked<LB>>::Output;
+}
+
+/// Unchecked version of [`MergeSorted`]: merges two lists assumed to be
+/// sorted, keeping every element of both.
+pub trait MergeSortedUnchecked<Other: HList>: HList {
+    /// The elements of both lists.
+    type Output: HList;
+}
+
+impl<Other: HList> MergeSortedUnchecked<Other> for HNil {
+    type Output = Other;
+}
+
+impl<H, T: HList> MergeSortedUnchecked<HNil> for HCons<H, T> {
+    type Output = Self;
+}
+
+impl<HA, TA: HList, HB, TB: HList> MergeSortedUnchecked<HCons<HB, TB>> for HCons<HA, TA>
+where
+    // Compare the two heads, then dispatch
+    HA: TypeCmp<HB>,
+    Self: MergeSortedByOrder<HCons<HB, TB>, TypeCompare<HA, HB>>,
+{
+    type Output = <Self as MergeSortedByOrder<HCons<HB, TB>, TypeCompare<HA, HB>>>::Output;
+}
+
+/// Internal dispatch for [`MergeSortedUnchecked`] on the ordering of the two heads.
+pub trait MergeSortedByOrder<Rhs: HList, Ord>: HList {
+    /// The sorted elements of both lists.
+    type Output: HList;
+}
+
+impl<HA, TA: HList, HB, TB: HList> MergeSortedByOrder<HCons<HB, TB>, Less> for HCons<HA, TA>
+where
+    // HA < HB -> take HA
+    TA: MergeSortedUnchecked<HCons<HB, TB>>,
+{
+    type Output = HCons<HA, <TA as MergeSortedUnchecked<HCons<HB, TB>>>::Output>;
+}
+
+impl<HA, TA: HList, HB, TB: HList> MergeSortedByOrder<HCons<HB, TB>, Greater> for HCons<HA, TA>
+where
+    // HA > HB -> take HB
+    HCons<HA, TA>: MergeSortedUnchecked<TB>,
+{
+    type Output = HCons<HB, <HCons<HA, TA> as MergeSortedUnchecked<TB>>::Output>;
+}
+
+impl<HA, TA: HList, HB, TB: HList> MergeSortedByOrder<HCons<HB, TB>, Equal> for HCons<HA, TA>
+where
+    // HA == HB -> take both, HA first
+    TA: MergeSortedUnchecked<TB>,
+{
+    type Output = HCons<HA, HCons<HB, <TA as MergeSortedUnchecked<TB>>::Output>>;
+}
@@ -0,0 +1,86 @@
+//! Value-level proof tokens for sorted lists.
+
+use crate::{Difference, Intersect, SortedHList, Union};
+use core::marker::PhantomData;
+
+/// A zero-sized proof that `L` is a [`SortedHList`].
+///
+/// Set operations are available as methods, so they can be chained as
+/// expressions and the resulting list type inferred at the binding site,
+/// instead of being spelled out as associated types in `where` clauses.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::{sorted, Sorted};
+/// # use typenum::{U1, U2, U3, U4};
+/// let a = sorted::<mk_hlist!(U1, U2, U3)>();
+/// let b = sorted::<mk_hlist!(U2, U3, U4)>();
+/// let common: Sorted<mk_hlist!(U2, U3)> = a.intersect(b);
+/// ```
+pub struct Sorted<L: SortedHList>(PhantomData<L>);
+
+impl<L: SortedHList> Sorted<L> {
+    /// Create the proof token for `L`.
+    #[must_use = "this type-level computation produces an output; ignoring it is likely a mistake"]
+    pub const fn new() -> Self {
+        Sorted(PhantomData)
+    }
+
+    /// Intersect with another sorted list.
+    #[must_use = "this type-level computation produces an output; ignoring it is likely a mistake"]
+    pub const fn intersect<R: SortedHList>(
+        self,
+        _other: Sorted<R>,
+    ) -> Sorted<<L as Intersect<R>>::Output>
+    where
+        L: Intersect<R>,
+        <L as Intersect<R>>::Output: SortedHList,
+    {
+        Sorted::new()
+    }
+
+    /// Union with another sorted list.
+    #[must_use = "this type-level computation produces an output; ignoring it is likely a mistake"]
+    pub const fn union<R: SortedHList>(self, _other: Sorted<R>) -> Sorted<<L as Union<R>>::Output>
+    where
+        L: Union<R>,
+        <L as Union<R>>::Output: SortedHList,
+    {
+        Sorted::new()
+    }
+
+    /// The elements of this list missing from another sorted list.
+    #[must_use = "this type-level computation produces an output; ignoring it is likely a mistake"]
+    pub const fn difference<R: SortedHList>(
+        self,
+        _other: Sorted<R>,
+    ) -> Sorted<<L as Difference<R>>::Output>
+    where
+        L: Difference<R>,
+        <L as Difference<R>>::Output: SortedHList,
+    {
+        Sorted::new()
+    }
+}
+
+impl<L: SortedHList> Clone for Sorted<L> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<L: SortedHList> Copy for Sorted<L> {}
+
+impl<L: SortedHList> Default for Sorted<L> {
+    fn default() -> Self {
+        Sorted::new()
+    }
+}
+
+/// Create the [`Sorted`] proof token for `L`.
+#[must_use = "this type-level computation produces an output; ignoring it is likely a mistake"]
+pub const fn sorted<L: SortedHList>() -> Sorted<L> {
+    Sorted::new()
+}
@@ -0,0 +1,85 @@
+//! A type-level option, for queries that may have no answer.
+//!
+//! Total queries such as [`Find`](crate::Find) return [`TSome`] or
+//! [`TNone`], and the combinators below post-process the result without
+//! dispatching on the variant by hand:
+//!
+//! ```rust
+//! # use sorted_hlist::prelude::*;
+//! # use sorted_hlist::{Find, IsEven, UnwrapOr};
+//! # use typenum::{U0, U1, U3, U5};
+//! type First = <mk_hlist!(U1, U3, U5) as Find<IsEven>>::Output;
+//! assert_type_eq::<<First as UnwrapOr<U0>>::Output, U0>();
+//! ```
+
+use crate::TypeFn;
+use typenum::{Bit, B0, B1};
+
+/// Marker trait for the type-level options [`TSome`] and [`TNone`].
+pub trait TOption {}
+
+/// A type-level option holding `T`.
+pub struct TSome<T>(core::marker::PhantomData<T>);
+
+/// The empty type-level option.
+pub struct TNone;
+
+impl<T> TOption for TSome<T> {}
+impl TOption for TNone {}
+
+/// Apply the [`TypeFn`] `F` to the value inside a [`TSome`]; [`TNone`] stays
+/// empty.
+pub trait TOptionMap<F>: TOption {
+    /// `TSome<F(T)>` or `TNone`.
+    type Output: TOption;
+}
+
+impl<F: TypeFn<T>, T> TOptionMap<F> for TSome<T> {
+    type Output = TSome<<F as TypeFn<T>>::Output>;
+}
+
+impl<F> TOptionMap<F> for TNone {
+    type Output = TNone;
+}
+
+/// The value inside a [`TSome`], or `Default` for [`TNone`].
+pub trait UnwrapOr<Default>: TOption {
+    /// The contained value or `Default`.
+    type Output;
+}
+
+impl<T, Default> UnwrapOr<Default> for TSome<T> {
+    type Output = T;
+}
+
+impl<Default> UnwrapOr<Default> for TNone {
+    type Output = Default;
+}
+
+/// Whether an option is a [`TSome`], as a `typenum` `Bit`.
+pub trait IsSome: TOption {
+    /// `B1` for `TSome`, `B0` for `TNone`.
+    type Output: Bit;
+}
+
+impl<T> IsSome for TSome<T> {
+    type Output = B1;
+}
+
+impl IsSome for TNone {
+    type Output = B0;
+}
+
+/// Whether an option is [`TNone`], as a `typenum` `Bit`.
+pub trait IsNone: TOption {
+    /// `B1` for `TNone`, `B0` for `TSome`.
+    type Output: Bit;
+}
+
+impl<T> IsNone for TSome<T> {
+    type Output = B0;
+}
+
+impl IsNone for TNone {
+    type Output = B1;
+}
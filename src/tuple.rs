@@ -0,0 +1,58 @@
+//! Conversions between tuple types and HLists.
+//!
+//! Both directions are implemented for tuples of up to 12 elements.
+
+use crate::{mk_hlist, HList, HNil};
+
+/// Convert a tuple type into the HList of its element types, in order.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::FromTuple;
+/// type L = <(u8, bool, char) as FromTuple>::Output;
+/// // L = mk_hlist!(u8, bool, char)
+/// ```
+pub trait FromTuple {
+    /// The equivalent HList.
+    type Output: HList;
+}
+
+/// Convert an HList into the tuple type of its element types, in order.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::IntoTuple;
+/// let t: <mk_hlist!(u8, bool) as IntoTuple>::Output = (1u8, true);
+/// ```
+pub trait IntoTuple: HList {
+    /// The equivalent tuple type.
+    type Output;
+}
+
+macro_rules! tuple_impls {
+    () => {
+        impl FromTuple for () {
+            type Output = HNil;
+        }
+
+        impl IntoTuple for HNil {
+            type Output = ();
+        }
+    };
+    ($head:ident $(, $tail:ident)*) => {
+        impl<$head $(, $tail)*> FromTuple for ($head, $($tail,)*) {
+            type Output = mk_hlist!($head $(, $tail)*);
+        }
+
+        impl<$head $(, $tail)*> IntoTuple for mk_hlist!($head $(, $tail)*) {
+            type Output = ($head, $($tail,)*);
+        }
+
+        tuple_impls!($($tail),*);
+    };
+}
+
+tuple_impls!(A, B, C, D, E, F, G, H, I, J, K, L);
@@ -0,0 +1,101 @@
+//! Runtime `TypeId`s of the elements of sorted lists.
+//!
+//! Requires the `std` feature.
+
+use crate::{HCons, HList, HNil, SortedHList};
+use alloc::boxed::Box;
+use alloc::collections::BTreeSet;
+use alloc::vec::Vec;
+use core::any::TypeId;
+use std::collections::HashMap;
+use std::sync::{OnceLock, RwLock};
+
+/// The `TypeId`s of the elements of a sorted list, in list order.
+///
+/// The slice for each list type is built on first use and then cached, so
+/// repeated calls return the same slice.  Cache hits only take a shared
+/// read lock; the exclusive lock is taken once per list type, to insert it.
+///
+/// Each slice is leaked to obtain the `'static` lifetime: every distinct
+/// list type queried keeps one allocation of `LEN` `TypeId`s for the rest
+/// of the process.  This is bounded by the number of list types in the
+/// program.  Requires the `std` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::AsSortedTypeIds;
+/// # use typenum::{U1, U2};
+/// # use core::any::TypeId;
+/// let ids = <mk_hlist!(U1, U2) as AsSortedTypeIds>::type_ids();
+/// assert_eq!(ids, [TypeId::of::<U1>(), TypeId::of::<U2>()]);
+/// ```
+pub trait AsSortedTypeIds: SortedHList {
+    /// The element `TypeId`s, in sorted order.
+    fn type_ids() -> &'static [TypeId];
+
+    /// The distinct element `TypeId`s as a set, for membership tests and set
+    /// operations at runtime.
+    ///
+    /// The set is ordered by `TypeId`, not by the list order.
+    ///
+    /// ```rust
+    /// # use sorted_hlist::prelude::*;
+    /// # use sorted_hlist::AsSortedTypeIds;
+    /// # use typenum::{U1, U2, U3};
+    /// # use core::any::TypeId;
+    /// let ids = <mk_hlist!(U1, U2, U2) as AsSortedTypeIds>::type_id_set();
+    /// assert_eq!(ids.len(), 2);
+    /// assert!(ids.contains(&TypeId::of::<U2>()));
+    /// assert!(!ids.contains(&TypeId::of::<U3>()));
+    /// ```
+    fn type_id_set() -> BTreeSet<TypeId> {
+        Self::type_ids().iter().copied().collect()
+    }
+}
+
+impl<L: SortedHList + PushTypeIds + 'static> AsSortedTypeIds for L {
+    fn type_ids() -> &'static [TypeId] {
+        static CACHE: OnceLock<RwLock<HashMap<TypeId, &'static [TypeId]>>> = OnceLock::new();
+
+        let cache = CACHE.get_or_init(Default::default);
+        let key = TypeId::of::<L>();
+        let cached = cache
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .get(&key)
+            .copied();
+        if let Some(ids) = cached {
+            return ids;
+        }
+
+        // another thread may have inserted the slice since the read
+        let mut cache = cache
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        cache.entry(key).or_insert_with(|| {
+            let mut ids = Vec::with_capacity(L::LEN);
+            L::push_type_ids(&mut ids);
+            Box::leak(ids.into_boxed_slice())
+        })
+    }
+}
+
+/// Internal helper for [`AsSortedTypeIds`]: appends the `TypeId`s of the
+/// elements of `Self`.
+pub trait PushTypeIds: HList {
+    /// Append the element `TypeId`s to `ids`, in list order.
+    fn push_type_ids(ids: &mut Vec<TypeId>);
+}
+
+impl PushTypeIds for HNil {
+    fn push_type_ids(_: &mut Vec<TypeId>) {}
+}
+
+impl<H: 'static, T: PushTypeIds> PushTypeIds for HCons<H, T> {
+    fn push_type_ids(ids: &mut Vec<TypeId>) {
+        ids.push(TypeId::of::<H>());
+        T::push_type_ids(ids);
+    }
+}
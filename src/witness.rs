@@ -0,0 +1,197 @@
+//! Free functions returning `PhantomData` witnesses of type-level results,
+//! and the [`TypeEq`] assertion for checking them.
+//!
+//! Each function produces a `PhantomData` of the computed list, so the
+//! result can be passed on to further generic functions and inference does
+//! the plumbing:
+//!
+//! ```rust
+//! # use core::marker::PhantomData;
+//! # use sorted_hlist::prelude::*;
+//! # use typenum::{U1, U2, U3, U4};
+//! fn takes_sorted<L: SortedHList>(_: PhantomData<L>) {}
+//!
+//! takes_sorted(intersect::<mk_hlist!(U1, U2, U3), mk_hlist!(U2, U3, U4)>());
+//! ```
+
+use crate::{Difference, ExactLen, Intersect, SortedHList, Union};
+use core::marker::PhantomData;
+use typenum::Unsigned;
+
+/// Witness that `L` is a [`SortedHList`].
+#[must_use = "this type-level computation produces an output; ignoring it is likely a mistake"]
+pub const fn is_sorted_witness<L: SortedHList>() -> PhantomData<L> {
+    PhantomData
+}
+
+/// Witness of the [`Intersect`]ion of `A` and `B`.
+///
+/// Discarding the witness is flagged by the `unused_must_use` lint:
+///
+/// ```rust,compile_fail
+/// #![deny(unused_must_use)]
+/// # use sorted_hlist::prelude::*;
+/// # use typenum::{U1, U2};
+/// fn main() {
+///     intersect::<mk_hlist!(U1, U2), mk_hlist!(U2)>();
+/// }
+/// ```
+#[must_use = "this type-level computation produces an output; ignoring it is likely a mistake"]
+pub const fn intersect<A, B>() -> PhantomData<<A as Intersect<B>>::Output>
+where
+    A: Intersect<B>,
+    B: SortedHList,
+{
+    PhantomData
+}
+
+/// Witness of the [`Union`] of `A` and `B`.
+///
+/// Both operands must be sorted:
+///
+/// ```rust,compile_fail
+/// # use sorted_hlist::prelude::*;
+/// # use typenum::{U1, U2, U3};
+/// let _ = union::<mk_hlist!(U3, U1), mk_hlist!(U2)>();
+/// ```
+#[must_use = "this type-level computation produces an output; ignoring it is likely a mistake"]
+pub const fn union<A, B>() -> PhantomData<<A as Union<B>>::Output>
+where
+    A: SortedHList + Union<B>,
+    B: SortedHList,
+{
+    PhantomData
+}
+
+/// Witness of the [`Difference`] of `A` and `B`: the elements of `A`
+/// missing from `B`.
+///
+/// Both operands must be sorted:
+///
+/// ```rust,compile_fail
+/// # use sorted_hlist::prelude::*;
+/// # use typenum::{U1, U2, U3};
+/// let _ = difference::<mk_hlist!(U3, U1), mk_hlist!(U2)>();
+/// ```
+#[must_use = "this type-level computation produces an output; ignoring it is likely a mistake"]
+pub const fn difference<A, B>() -> PhantomData<<A as Difference<B>>::Output>
+where
+    A: SortedHList + Difference<B>,
+    B: SortedHList,
+{
+    PhantomData
+}
+
+/// Proof that `Self` and `B` are the same type.
+///
+/// Usable in `where` clauses to pin down a computed type:
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use typenum::{U1, U2, U3};
+/// fn common<A, B>()
+/// where
+///     A: Intersect<B>,
+///     B: SortedHList,
+///     <A as Intersect<B>>::Output: TypeEq<mk_hlist!(U2)>,
+/// {
+/// }
+/// common::<mk_hlist!(U1, U2), mk_hlist!(U2, U3)>();
+/// ```
+pub trait TypeEq<B>: Sized {}
+
+impl<T> TypeEq<T> for T {}
+
+/// Assert at compile time that `A` and `B` are the same type.
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use sorted_hlist::Sort;
+/// # use typenum::{U1, U2};
+/// const _: () = assert_type_eq::<<mk_hlist!(U2, U1) as Sort>::Output, mk_hlist!(U1, U2)>();
+/// ```
+///
+/// ```rust,compile_fail
+/// # use sorted_hlist::prelude::*;
+/// # use typenum::{U1, U2};
+/// const _: () = assert_type_eq::<mk_hlist!(U2, U1), mk_hlist!(U1, U2)>();
+/// ```
+pub const fn assert_type_eq<A: TypeEq<B>, B>() {}
+
+/// A zero-sized assertion that `L` is a [`SortedHList`].
+///
+/// Naming the type anywhere, e.g. in a `const` item, checks the bound
+/// without adding it to any function signature.
+pub struct AssertSorted<L: SortedHList>(PhantomData<L>);
+
+impl<L: SortedHList> AssertSorted<L> {
+    /// Assert that `L` is sorted.
+    pub const fn new() -> Self {
+        AssertSorted(PhantomData)
+    }
+}
+
+impl<L: SortedHList> Clone for AssertSorted<L> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<L: SortedHList> Copy for AssertSorted<L> {}
+
+impl<L: SortedHList> Default for AssertSorted<L> {
+    fn default() -> Self {
+        AssertSorted::new()
+    }
+}
+
+/// Assert at compile time that `L` is a [`SortedHList`].
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use typenum::{U1, U2, U3};
+/// const _: () = assert_sorted::<mk_hlist!(U1, U2, U3)>();
+/// ```
+///
+/// ```rust,compile_fail
+/// # use sorted_hlist::prelude::*;
+/// # use typenum::{U1, U2};
+/// const _: () = assert_sorted::<mk_hlist!(U2, U1)>();
+/// ```
+pub const fn assert_sorted<L: SortedHList>() {}
+
+/// A zero-sized assertion that `L` has exactly `N` elements.
+///
+/// ```rust
+/// # use sorted_hlist::prelude::*;
+/// # use typenum::U2;
+/// const _: AssertLen<mk_hlist!(u8, u16), U2> = AssertLen::new();
+/// ```
+///
+/// ```rust,compile_fail
+/// # use sorted_hlist::prelude::*;
+/// # use typenum::U3;
+/// const _: AssertLen<mk_hlist!(u8, u16), U3> = AssertLen::new();
+/// ```
+pub struct AssertLen<L: ExactLen<N>, N: Unsigned>(PhantomData<(L, N)>);
+
+impl<L: ExactLen<N>, N: Unsigned> AssertLen<L, N> {
+    /// Assert that `L` has `N` elements.
+    pub const fn new() -> Self {
+        AssertLen(PhantomData)
+    }
+}
+
+impl<L: ExactLen<N>, N: Unsigned> Clone for AssertLen<L, N> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<L: ExactLen<N>, N: Unsigned> Copy for AssertLen<L, N> {}
+
+impl<L: ExactLen<N>, N: Unsigned> Default for AssertLen<L, N> {
+    fn default() -> Self {
+        AssertLen::new()
+    }
+}
@@ -0,0 +1,83 @@
+use sorted_hlist::prelude::*;
+use sorted_hlist::{FromBitmask, IntersectViaBitmask, SetBits, Sort, ToBitmask};
+use typenum::{
+    Shleft, Unsigned, U0, U1, U1000, U11, U164, U2, U3, U32, U4, U40, U5, U62, U63, U7, U8, U9,
+};
+
+const fn sorted<L: SortedHList>() {}
+
+#[test]
+fn mask_of_empty_list() {
+    assert_type_eq::<<mk_hlist!() as ToBitmask>::Output, U0>();
+}
+
+#[test]
+fn mask_of_singleton() {
+    assert_type_eq::<<mk_hlist!(U3) as ToBitmask>::Output, U8>();
+    assert_type_eq::<<mk_hlist!(U0) as ToBitmask>::Output, U1>();
+}
+
+#[test]
+fn mask_of_several_elements() {
+    type L = mk_hlist!(U1, U4, U9, U63);
+    let expected = (1u64 << 1) | (1 << 4) | (1 << 9) | (1 << 63);
+    assert_eq!(<L as ToBitmask>::Output::U64, expected);
+}
+
+#[test]
+fn mask_ignores_order() {
+    type A = mk_hlist!(U7, U2, U5);
+    type B = <A as Sort>::Output;
+    assert_type_eq::<<A as ToBitmask>::Output, <B as ToBitmask>::Output>();
+    assert_type_eq::<<B as ToBitmask>::Output, U164>();
+}
+
+#[test]
+fn decode_masks() {
+    assert_type_eq::<<U0 as FromBitmask>::Output, mk_hlist!()>();
+    assert_type_eq::<<U1 as FromBitmask>::Output, mk_hlist!(U0)>();
+    assert_type_eq::<<U164 as FromBitmask>::Output, mk_hlist!(U2, U5, U7)>();
+    sorted::<<U164 as FromBitmask>::Output>();
+}
+
+#[test]
+fn decode_top_bit() {
+    type Top = Shleft<U1, U63>;
+    assert_type_eq::<<Top as FromBitmask>::Output, mk_hlist!(U63)>();
+    type Ends = <mk_hlist!(U0, U63) as ToBitmask>::Output;
+    assert_type_eq::<<Ends as FromBitmask>::Output, mk_hlist!(U0, U63)>();
+}
+
+#[test]
+fn bitmask_round_trips() {
+    type L = mk_hlist!(U1, U4, U9, U40);
+    assert_type_eq::<<<L as ToBitmask>::Output as FromBitmask>::Output, L>();
+    assert_type_eq::<<<U1000 as FromBitmask>::Output as ToBitmask>::Output, U1000>();
+    assert_type_eq::<<<mk_hlist!() as ToBitmask>::Output as FromBitmask>::Output, mk_hlist!()>();
+}
+
+#[test]
+fn bitmask_intersection_agrees_with_intersect() {
+    type A = mk_hlist!(U1, U3, U5, U40);
+    type B = mk_hlist!(U3, U4, U5, U63);
+    type C = mk_hlist!(U0, U2, U62);
+    // overlapping
+    assert_type_eq::<<A as IntersectViaBitmask<B>>::Output, <A as Intersect<B>>::Output>();
+    // disjoint
+    assert_type_eq::<<A as IntersectViaBitmask<C>>::Output, <A as Intersect<C>>::Output>();
+    assert_type_eq::<<A as IntersectViaBitmask<C>>::Output, mk_hlist!()>();
+    // identical
+    assert_type_eq::<<B as IntersectViaBitmask<B>>::Output, <B as Intersect<B>>::Output>();
+    // empty
+    assert_type_eq::<<mk_hlist!() as IntersectViaBitmask<A>>::Output, mk_hlist!()>();
+}
+
+#[test]
+fn set_bits_of_constants() {
+    assert_type_eq::<SetBits<U0>, mk_hlist!()>();
+    assert_type_eq::<SetBits<U32>, mk_hlist!(U5)>();
+    // 0b1011
+    assert_type_eq::<SetBits<U11>, mk_hlist!(U0, U1, U3)>();
+    assert_type_eq::<<SetBits<U11> as ToBitmask>::Output, U11>();
+    assert_type_eq::<<SetBits<U1000> as ToBitmask>::Output, U1000>();
+}
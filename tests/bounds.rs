@@ -0,0 +1,179 @@
+use sorted_hlist::prelude::*;
+use sorted_hlist::{
+    Deltas, DeltasUnchecked, FillGaps, IntersectFast, IsContiguous, LowerBound, MaxGap, MaxOf,
+    MinOf, RangeList, SafeIndexOf, TNone, TSome, UpperBound,
+};
+use typenum::{
+    Diff, Unsigned, B0, B1, U0, U1, U10, U1000, U1001, U2, U3, U4, U4096, U48, U5, U6, U7, U8,
+    U8192, U9, U999,
+};
+
+#[test]
+fn bounds_present() {
+    type L = mk_hlist!(U1, U3, U5, U7);
+    assert_type_eq::<<L as LowerBound<U5>>::Output, U2>();
+    assert_type_eq::<<L as UpperBound<U5>>::Output, U3>();
+}
+
+#[test]
+fn bounds_absent() {
+    type L = mk_hlist!(U1, U3, U5, U7);
+    assert_type_eq::<<L as LowerBound<U4>>::Output, U2>();
+    assert_type_eq::<<L as UpperBound<U4>>::Output, U2>();
+    assert_type_eq::<<L as LowerBound<U0>>::Output, U0>();
+    assert_type_eq::<<L as UpperBound<U0>>::Output, U0>();
+}
+
+#[test]
+fn bounds_past_the_end() {
+    type L = mk_hlist!(U1, U3, U5);
+    assert_type_eq::<<L as LowerBound<U6>>::Output, U3>();
+    assert_type_eq::<<L as UpperBound<U5>>::Output, U3>();
+}
+
+#[test]
+fn bounds_empty() {
+    type L = mk_hlist!();
+    assert_type_eq::<<L as LowerBound<U1>>::Output, U0>();
+    assert_type_eq::<<L as UpperBound<U1>>::Output, U0>();
+}
+
+#[test]
+fn bounds_duplicates_give_multiplicity() {
+    type L = mk_hlist!(U1, U2, U2, U2, U3);
+    type Lower = <L as LowerBound<U2>>::Output;
+    type Upper = <L as UpperBound<U2>>::Output;
+    assert_type_eq::<Lower, U1>();
+    assert_type_eq::<Upper, U4>();
+    assert_type_eq::<Diff<Upper, Lower>, U3>();
+    assert_eq!(<Diff<Upper, Lower>>::USIZE, 3);
+}
+
+#[test]
+fn min_and_max() {
+    type L = mk_hlist!(U2, U4, U7);
+    assert_type_eq::<MinOf<L>, U2>();
+    assert_type_eq::<MaxOf<L>, U7>();
+    assert_type_eq::<MinOf<mk_hlist!(U3)>, U3>();
+    assert_type_eq::<MaxOf<mk_hlist!(U3)>, U3>();
+}
+
+#[test]
+fn intersect_fast_disjoint_ranges() {
+    type Low = mk_hlist!(U1, U2, U3);
+    type High = mk_hlist!(U7, U8, U9);
+    assert_type_eq::<<Low as IntersectFast<High>>::Output, mk_hlist!()>();
+    assert_type_eq::<<High as IntersectFast<Low>>::Output, mk_hlist!()>();
+    assert_type_eq::<<Low as IntersectFast<High>>::Output, <Low as Intersect<High>>::Output>();
+}
+
+#[test]
+fn intersect_fast_overlapping_ranges() {
+    // the ranges overlap but the elements do not: no shortcut applies
+    type Odd = mk_hlist!(U1, U3, U5, U7);
+    type Even = mk_hlist!(U2, U4, U6, U8);
+    assert_type_eq::<<Odd as IntersectFast<Even>>::Output, mk_hlist!()>();
+    assert_type_eq::<<Odd as IntersectFast<Even>>::Output, <Odd as Intersect<Even>>::Output>();
+
+    // ranges that touch in a single element
+    type A = mk_hlist!(U1, U2, U4);
+    type B = mk_hlist!(U4, U5, U6);
+    assert_type_eq::<<A as IntersectFast<B>>::Output, mk_hlist!(U4)>();
+    assert_type_eq::<<B as IntersectFast<A>>::Output, mk_hlist!(U4)>();
+
+    type C = mk_hlist!(U1, U3, U5, U6, U9);
+    assert_type_eq::<<C as IntersectFast<B>>::Output, <C as Intersect<B>>::Output>();
+}
+
+#[test]
+fn intersect_fast_empty() {
+    type L = mk_hlist!(U1, U2);
+    assert_type_eq::<<mk_hlist!() as IntersectFast<L>>::Output, mk_hlist!()>();
+    assert_type_eq::<<L as IntersectFast<mk_hlist!()>>::Output, mk_hlist!()>();
+}
+
+#[test]
+fn safe_index_of() {
+    type L = mk_hlist!(U2, U4, U6);
+    assert_type_eq::<<L as SafeIndexOf<U2>>::Output, TSome<U0>>();
+    assert_type_eq::<<L as SafeIndexOf<U6>>::Output, TSome<U2>>();
+    // absent below the minimum, in between, and above the maximum
+    assert_type_eq::<<L as SafeIndexOf<U1>>::Output, TNone>();
+    assert_type_eq::<<L as SafeIndexOf<U5>>::Output, TNone>();
+    assert_type_eq::<<L as SafeIndexOf<U9>>::Output, TNone>();
+    assert_type_eq::<<mk_hlist!() as SafeIndexOf<U1>>::Output, TNone>();
+}
+
+#[test]
+fn deltas() {
+    // contiguous run
+    assert_type_eq::<<mk_hlist!(U3, U4, U5, U6) as Deltas>::Output, mk_hlist!(U1, U1, U1)>();
+    // a large gap
+    type Map = mk_hlist!(U0, U1, U1000, U1001);
+    assert_type_eq::<<Map as Deltas>::Output, mk_hlist!(U1, U999, U1)>();
+    // duplicates
+    assert_type_eq::<<mk_hlist!(U2, U2, U5) as Deltas>::Output, mk_hlist!(U0, U3)>();
+    // short lists
+    assert_type_eq::<<mk_hlist!(U7) as Deltas>::Output, mk_hlist!()>();
+    assert_type_eq::<<mk_hlist!() as Deltas>::Output, mk_hlist!()>();
+}
+
+#[test]
+fn deltas_of_long_lists() {
+    type L = RangeList<U1, U48>;
+    assert_type_eq::<<L as IsContiguous>::Output, B1>();
+    assert_type_eq::<<L as MaxGap>::Output, U1>();
+    assert_eq!(<<L as Deltas>::Output as HList>::LEN, 47);
+}
+
+#[test]
+fn deltas_unchecked_skips_the_sortedness_proof() {
+    type L = mk_hlist!(U1, U3, U7);
+    assert_type_eq::<<L as DeltasUnchecked>::Output, <L as Deltas>::Output>();
+}
+
+#[test]
+fn max_gap() {
+    assert_type_eq::<<mk_hlist!(U3, U4, U5, U6) as MaxGap>::Output, U1>();
+    assert_type_eq::<<mk_hlist!(U0, U1, U9, U10) as MaxGap>::Output, U8>();
+    // a zero gap from the duplicate is not the maximum
+    assert_type_eq::<<mk_hlist!(U2, U2, U5, U6) as MaxGap>::Output, U3>();
+    assert_type_eq::<<mk_hlist!(U4, U4, U4) as MaxGap>::Output, U0>();
+    assert_type_eq::<<mk_hlist!(U7) as MaxGap>::Output, U0>();
+    assert_type_eq::<<mk_hlist!() as MaxGap>::Output, U0>();
+
+    type Pages = mk_hlist!(U0, U4096, U8192);
+    const _: () = assert!(<Pages as MaxGap>::Output::USIZE <= 4096);
+}
+
+#[test]
+fn is_contiguous() {
+    assert_type_eq::<<mk_hlist!(U3, U4, U5, U6) as IsContiguous>::Output, B1>();
+    assert_type_eq::<<mk_hlist!(U3, U4, U6) as IsContiguous>::Output, B0>();
+    // a duplicate
+    assert_type_eq::<<mk_hlist!(U3, U4, U4, U5) as IsContiguous>::Output, B0>();
+    // short lists
+    assert_type_eq::<<mk_hlist!(U9) as IsContiguous>::Output, B1>();
+    assert_type_eq::<<mk_hlist!() as IsContiguous>::Output, B1>();
+}
+
+#[test]
+fn range_list() {
+    assert_type_eq::<RangeList<U3, U3>, mk_hlist!(U3)>();
+    assert_type_eq::<RangeList<U0, U4>, mk_hlist!(U0, U1, U2, U3, U4)>();
+    assert_type_eq::<RangeList<U4, U3>, mk_hlist!()>();
+}
+
+#[test]
+fn fill_gaps() {
+    type Sparse = mk_hlist!(U2, U5, U6, U9);
+    type Dense = <Sparse as FillGaps>::Output;
+    assert_type_eq::<Dense, mk_hlist!(U2, U3, U4, U5, U6, U7, U8, U9)>();
+    assert_type_eq::<Dense, RangeList<MinOf<Sparse>, MaxOf<Sparse>>>();
+    // idempotent
+    assert_type_eq::<<Dense as FillGaps>::Output, Dense>();
+    // singleton
+    assert_type_eq::<<mk_hlist!(U7) as FillGaps>::Output, mk_hlist!(U7)>();
+    // duplicates collapse
+    assert_type_eq::<<mk_hlist!(U1, U1, U3) as FillGaps>::Output, mk_hlist!(U1, U2, U3)>();
+}
@@ -0,0 +1,95 @@
+use sorted_hlist::prelude::*;
+use sorted_hlist::{Equal, Greater, Less, LowerBound, OrderKey, TypeCompare};
+use typenum::{U0, U1, U2, U3};
+
+/// Markers with a hand-written ordering: `Low < Mid < High`.
+struct Low;
+struct Mid;
+struct High;
+
+macro_rules! order {
+    ($($a:ty, $b:ty => $ord:ty;)*) => {
+        $(impl TypeCmp<$b> for $a { type Output = $ord; })*
+    };
+}
+
+order! {
+    Low, Low => Equal;
+    Low, Mid => Less;
+    Low, High => Less;
+    Mid, Low => Greater;
+    Mid, Mid => Equal;
+    Mid, High => Less;
+    High, Low => Greater;
+    High, Mid => Greater;
+    High, High => Equal;
+}
+
+/// Markers ordered through `typenum::Cmp` as before [`TypeCmp`] existed,
+/// migrated by declaring each marker as its own [`OrderKey`].
+struct Before;
+struct After;
+
+macro_rules! legacy_order {
+    ($($a:ty, $b:ty => $ord:ty;)*) => {
+        $(impl typenum::Cmp<$b> for $a {
+            type Output = $ord;
+            fn compare<IM: typenum::private::InternalMarker>(&self, _: &$b) -> $ord {
+                Default::default()
+            }
+        })*
+    };
+}
+
+legacy_order! {
+    Before, Before => Equal;
+    Before, After => Less;
+    After, Before => Greater;
+    After, After => Equal;
+}
+
+impl OrderKey for Before {
+    type Key = Self;
+}
+impl OrderKey for After {
+    type Key = Self;
+}
+
+const fn sorted<L: SortedHList>() {}
+
+#[test]
+fn typenum_elements_use_blanket_impl() {
+    assert_type_eq::<TypeCompare<U1, U2>, Less>();
+    assert_type_eq::<TypeCompare<U2, U2>, Equal>();
+    assert_type_eq::<TypeCompare<U3, U0>, Greater>();
+}
+
+#[test]
+fn custom_ordering_sorts() {
+    sorted::<mk_hlist!(Low, Mid, High)>();
+    sorted::<mk_hlist!(Low, Low, High)>();
+}
+
+#[test]
+fn custom_ordering_intersects() {
+    type A = mk_hlist!(Low, Mid, High);
+    type B = mk_hlist!(Mid, High);
+    assert_type_eq::<<A as Intersect<B>>::Output, mk_hlist!(Mid, High)>();
+    assert_type_eq::<<mk_hlist!(Low, High) as Intersect<mk_hlist!(Mid)>>::Output, mk_hlist!()>();
+}
+
+#[test]
+fn custom_ordering_range_queries() {
+    type L = mk_hlist!(Low, Mid, Mid, High);
+    assert_type_eq::<<L as LowerBound<Mid>>::Output, U1>();
+    assert_type_eq::<<L as LowerBound<High>>::Output, U3>();
+}
+
+#[test]
+fn legacy_cmp_ordering_migrates_through_self_key() {
+    assert_type_eq::<TypeCompare<Before, After>, Less>();
+    assert_type_eq::<TypeCompare<After, Before>, Greater>();
+    sorted::<mk_hlist!(Before, Before, After)>();
+    type A = mk_hlist!(Before, After);
+    assert_type_eq::<<A as Intersect<mk_hlist!(After)>>::Output, mk_hlist!(After)>();
+}
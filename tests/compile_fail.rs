@@ -0,0 +1,11 @@
+//! Negative tests: each file in `tests/ui` must fail to compile with the
+//! error recorded next to it in a `.stderr` snapshot.
+//!
+//! After an intended change to a diagnostic, regenerate the snapshots with
+//! `TRYBUILD=overwrite cargo test --test compile_fail`.
+
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,51 @@
+use sorted_hlist::prelude::*;
+use sorted_hlist::{AllSatisfy, Constraint, IsClone, IsCopy, IsDebug, IsSend, WhereAll};
+use typenum::{U1, U2, U3};
+
+trait Register {
+    const WIDTH: u32;
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Ctrl;
+#[derive(Debug, Clone, Copy)]
+struct Status;
+
+impl Register for Ctrl {
+    const WIDTH: u32 = 8;
+}
+impl Register for Status {
+    const WIDTH: u32 = 16;
+}
+
+struct IsRegister;
+impl<T: Register> Constraint<T> for IsRegister {}
+
+const fn all_satisfy<L: AllSatisfy<C>, C>() {}
+const fn where_all<L: WhereAll<Cs>, Cs: sorted_hlist::HList>() {}
+
+#[test]
+fn all_satisfy_core_constraints() {
+    all_satisfy::<mk_hlist!(U1, U2, U3), IsDebug>();
+    all_satisfy::<mk_hlist!(u8, bool, char), IsCopy>();
+    all_satisfy::<mk_hlist!(), IsRegister>();
+}
+
+#[test]
+fn where_all_multiple_constraints() {
+    fn debug_send<L: WhereAll<mk_hlist!(IsDebug, IsSend)>>() {}
+    debug_send::<mk_hlist!(U1, u8, Ctrl)>();
+    where_all::<mk_hlist!(Ctrl, Status), mk_hlist!(IsDebug, IsClone, IsRegister)>();
+}
+
+#[test]
+fn where_all_no_constraints() {
+    where_all::<mk_hlist!(*const u8), mk_hlist!()>();
+}
+
+#[test]
+fn user_constraint() {
+    fn total_width<L: WhereAll<mk_hlist!(IsRegister)>>() {}
+    total_width::<mk_hlist!(Ctrl, Status)>();
+    assert_eq!(Ctrl::WIDTH + Status::WIDTH, 24);
+}
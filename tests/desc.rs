@@ -0,0 +1,41 @@
+use sorted_hlist::prelude::*;
+use sorted_hlist::{IntersectDesc, Reverse, ReverseSortedHList};
+use typenum::{U1, U2, U3, U4, U5, U6, U7};
+
+const fn descending<L: ReverseSortedHList>() {}
+const fn ascending<L: SortedHList>() {}
+
+#[test]
+fn reverse_sorted_lists() {
+    descending::<mk_hlist!()>();
+    descending::<mk_hlist!(U3)>();
+    descending::<mk_hlist!(U5, U3, U3, U1)>();
+}
+
+#[test]
+fn reverse_flips_order() {
+    type Asc = mk_hlist!(U1, U2, U4);
+    type Desc = <Asc as Reverse>::Output;
+    assert_type_eq::<Desc, mk_hlist!(U4, U2, U1)>();
+    descending::<Desc>();
+    ascending::<<Desc as Reverse>::Output>();
+    assert_type_eq::<<mk_hlist!() as Reverse>::Output, mk_hlist!()>();
+}
+
+#[test]
+fn intersect_desc() {
+    type A = mk_hlist!(U7, U5, U3, U1);
+    type B = mk_hlist!(U6, U5, U4, U3);
+    assert_type_eq::<<A as IntersectDesc<B>>::Output, mk_hlist!(U5, U3)>();
+    assert_type_eq::<<A as IntersectDesc<mk_hlist!()>>::Output, mk_hlist!()>();
+}
+
+#[test]
+fn intersect_desc_matches_reversed_intersect() {
+    type A = mk_hlist!(U1, U2, U3, U5, U7);
+    type B = mk_hlist!(U2, U3, U4, U7);
+    type ViaDesc = <<A as Reverse>::Output as IntersectDesc<<B as Reverse>::Output>>::Output;
+    type ViaAsc = <<A as Intersect<B>>::Output as Reverse>::Output;
+    assert_type_eq::<ViaDesc, ViaAsc>();
+    assert_type_eq::<ViaDesc, mk_hlist!(U7, U3, U2)>();
+}
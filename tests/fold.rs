@@ -0,0 +1,131 @@
+use sorted_hlist::prelude::*;
+use sorted_hlist::{
+    AddFn, Duplicate, Fingerprint, FlatMap, FoldLeft, MaxFn, MulFn, OrFn, PrefixSums, Sort,
+    TypeBinaryFn, TypeFn, TypeScan,
+};
+use typenum::{Unsigned, U0, U1, U10, U2, U24, U3, U4, U5, U6, U7, U8, U9};
+
+struct UnionFn;
+
+impl<A: Union<B>, B: SortedHList> TypeBinaryFn<A, B> for UnionFn {
+    type Output = <A as Union<B>>::Output;
+}
+
+#[test]
+fn scan_prefix_sums() {
+    type L = mk_hlist!(U1, U2, U3, U4);
+    assert_type_eq::<<L as TypeScan<AddFn, U0>>::Output, mk_hlist!(U1, U3, U6, U10)>();
+    assert_type_eq::<<mk_hlist!() as TypeScan<AddFn, U0>>::Output, mk_hlist!()>();
+}
+
+#[test]
+fn scan_prefix_unions() {
+    type Sets = mk_hlist!(mk_hlist!(U2), mk_hlist!(U1), mk_hlist!(U2, U3));
+    assert_type_eq::<
+        <Sets as TypeScan<UnionFn, mk_hlist!()>>::Output,
+        mk_hlist!(mk_hlist!(U2), mk_hlist!(U1, U2), mk_hlist!(U1, U2, U3)),
+    >();
+}
+
+#[test]
+fn fingerprints() {
+    assert_eq!(<mk_hlist!() as Fingerprint>::Output::USIZE, 0);
+    assert_eq!(<mk_hlist!(U0) as Fingerprint>::Output::USIZE, 1);
+    // (0 * 31 + 1 + 1) * 31 + 2 + 1
+    assert_eq!(<mk_hlist!(U1, U2) as Fingerprint>::Output::USIZE, 65);
+    // (0 * 31 + 2 + 1) * 31 + 1 + 1
+    assert_eq!(<mk_hlist!(U2, U1) as Fingerprint>::Output::USIZE, 95);
+}
+
+#[test]
+fn fingerprints_of_permutations() {
+    type A = mk_hlist!(U3, U1, U2);
+    type B = mk_hlist!(U2, U3, U1);
+    assert_ne!(
+        <A as Fingerprint>::Output::USIZE,
+        <B as Fingerprint>::Output::USIZE
+    );
+    assert_eq!(
+        <<A as Sort>::Output as Fingerprint>::Output::USIZE,
+        <<B as Sort>::Output as Fingerprint>::Output::USIZE
+    );
+}
+
+const fn sorted<L: SortedHList>() {}
+
+#[test]
+fn prefix_sums() {
+    type Sizes = mk_hlist!(U4, U2, U0, U8);
+    assert_type_eq::<<Sizes as PrefixSums>::Output, mk_hlist!(U0, U4, U6, U6)>();
+    assert_type_eq::<<mk_hlist!(U3) as PrefixSums>::Output, mk_hlist!(U0)>();
+    assert_type_eq::<<mk_hlist!() as PrefixSums>::Output, mk_hlist!()>();
+
+    type Sorted = mk_hlist!(U1, U2, U2, U5);
+    sorted::<<Sorted as PrefixSums>::Output>();
+    assert_type_eq::<<Sorted as PrefixSums>::Output, mk_hlist!(U0, U1, U3, U5)>();
+}
+
+#[test]
+fn scan_running_maxima() {
+    type L = mk_hlist!(U2, U1, U4, U3, U6);
+    assert_type_eq::<<L as TypeScan<MaxFn, U0>>::Output, mk_hlist!(U2, U2, U4, U4, U6)>();
+    assert_type_eq::<<mk_hlist!() as TypeScan<MaxFn, U0>>::Output, mk_hlist!()>();
+}
+
+struct MinFn;
+
+impl<A: typenum::Min<B>, B> TypeBinaryFn<A, B> for MinFn {
+    type Output = typenum::Minimum<A, B>;
+}
+
+#[test]
+fn fold_left_provided_fns() {
+    type L = mk_hlist!(U2, U3, U4);
+    assert_type_eq::<<L as FoldLeft<AddFn, U0>>::Output, U9>();
+    assert_type_eq::<<L as FoldLeft<MulFn, U1>>::Output, U24>();
+    assert_type_eq::<<mk_hlist!(U1, U4, U5) as FoldLeft<OrFn, U0>>::Output, U5>();
+    assert_type_eq::<<L as FoldLeft<MaxFn, U0>>::Output, U4>();
+}
+
+#[test]
+fn fold_left_empty_is_init() {
+    assert_type_eq::<<mk_hlist!() as FoldLeft<AddFn, U7>>::Output, U7>();
+    assert_type_eq::<<mk_hlist!() as FoldLeft<MulFn, U1>>::Output, U1>();
+}
+
+#[test]
+fn fold_left_user_fn() {
+    type L = mk_hlist!(U6, U3, U8);
+    assert_type_eq::<<L as FoldLeft<MinFn, U10>>::Output, U3>();
+}
+
+// N copies of N, for a few small N
+struct Copies;
+
+impl TypeFn<U0> for Copies {
+    type Output = mk_hlist!();
+}
+
+impl TypeFn<U1> for Copies {
+    type Output = mk_hlist!(U1);
+}
+
+impl TypeFn<U3> for Copies {
+    type Output = mk_hlist!(U3, U3, U3);
+}
+
+#[test]
+fn flat_map_duplicate() {
+    assert_type_eq::<
+        <mk_hlist!(u8, bool) as FlatMap<Duplicate>>::Output,
+        mk_hlist!(u8, u8, bool, bool),
+    >();
+    assert_type_eq::<<mk_hlist!() as FlatMap<Duplicate>>::Output, mk_hlist!()>();
+}
+
+#[test]
+fn flat_map_varying_lengths() {
+    type L = mk_hlist!(U3, U0, U1, U0, U3);
+    assert_type_eq::<<L as FlatMap<Copies>>::Output, mk_hlist!(U3, U3, U3, U1, U3, U3, U3)>();
+    assert_type_eq::<<mk_hlist!(U0, U0) as FlatMap<Copies>>::Output, mk_hlist!()>();
+}
@@ -7,7 +7,10 @@ where
 {
 }
 
-use sorted_hlist::{mk_hlist, Intersect};
+use sorted_hlist::prelude::*;
+use sorted_hlist::{
+    intersect_all, Equal, Greater, Intersect3, Intersect4, IntersectByOrder, IntersectSame, Less,
+};
 use typenum::{U1, U2, U3, U4, U5, U6, U7, U8, U9};
 
 #[test]
@@ -68,3 +71,84 @@ fn intersection_large() {
     type Computed2 = <Computed1 as Intersect<D>>::Output;
     type_eq::<Computed2, Expected>();
 }
+
+#[test]
+fn intersection_cases_with_assert_type_eq() {
+    type A = mk_hlist!(U1, U2, U3);
+    type B = mk_hlist!(U2, U3, U4);
+    assert_type_eq::<<A as Intersect<B>>::Output, mk_hlist!(U2, U3)>();
+    assert_type_eq::<<mk_hlist!() as Intersect<B>>::Output, mk_hlist!()>();
+    assert_type_eq::<<mk_hlist!(U1, U2) as Intersect<mk_hlist!(U3, U4)>>::Output, mk_hlist!()>();
+    assert_type_eq::<<A as Intersect<A>>::Output, A>();
+    assert_type_eq::<
+        <mk_hlist!(U2, U3) as Intersect<mk_hlist!(U1, U2, U3, U4)>>::Output,
+        mk_hlist!(U2, U3),
+    >();
+}
+
+#[test]
+fn intersect_all_three_lists() {
+    type A = mk_hlist!(U1, U2, U3, U5);
+    type B = mk_hlist!(U2, U3, U4, U5);
+    type C = mk_hlist!(U3, U5, U6);
+    assert_type_eq::<intersect_all!(A, B, C), mk_hlist!(U3, U5)>();
+    assert_type_eq::<intersect_all!(A, B), <A as Intersect<B>>::Output>();
+}
+
+#[test]
+fn intersect3_and_intersect4_match_chaining() {
+    type A = mk_hlist!(U1, U2, U3, U4, U5, U6, U7, U8, U9);
+    type B = mk_hlist!(U1, U2, U3, U4, U5, U6, U7, U8, U9);
+    type C = mk_hlist!(U1, U2, U3, U4, U5, U6, U7, U8, U9);
+    type D = mk_hlist!(U1, U2, U3, U4, U5, U6, U7, U8, U9);
+
+    type Chained3 = <<A as Intersect<B>>::Output as Intersect<C>>::Output;
+    type Chained4 = <Chained3 as Intersect<D>>::Output;
+    assert_type_eq::<<A as Intersect3<B, C>>::Output, Chained3>();
+    assert_type_eq::<<A as Intersect4<B, C, D>>::Output, Chained4>();
+
+    type E = mk_hlist!(U2, U4, U6, U8);
+    type F = mk_hlist!(U1, U4, U8, U9);
+    assert_type_eq::<<A as Intersect3<E, F>>::Output, mk_hlist!(U4, U8)>();
+    assert_type_eq::<<A as Intersect4<E, F, mk_hlist!(U8)>>::Output, mk_hlist!(U8)>();
+}
+
+fn common<A, B, C>() -> core::marker::PhantomData<<A as Intersect3<B, C>>::Output>
+where
+    A: Intersect3<B, C>,
+    B: sorted_hlist::HList,
+    C: sorted_hlist::HList,
+{
+    core::marker::PhantomData
+}
+
+#[test]
+fn intersect3_as_single_bound() {
+    let _: core::marker::PhantomData<mk_hlist!(U3)> =
+        common::<mk_hlist!(U1, U3), mk_hlist!(U2, U3), mk_hlist!(U3, U4)>();
+}
+
+#[test]
+fn intersect_same_matches_intersect() {
+    type A = mk_hlist!(U1, U2, U3, U4, U5, U6, U7, U8, U9);
+    type B = mk_hlist!(U2, U2, U5);
+    assert_type_eq::<<A as IntersectSame>::Output, <A as Intersect<A>>::Output>();
+    assert_type_eq::<<B as IntersectSame>::Output, <B as Intersect<B>>::Output>();
+    assert_type_eq::<<mk_hlist!() as IntersectSame>::Output, mk_hlist!()>();
+}
+
+#[test]
+fn intersect_by_order_with_empty_side() {
+    type L = mk_hlist!(U1, U2);
+    assert_type_eq::<<L as IntersectByOrder<mk_hlist!(), Less>>::Output, mk_hlist!()>();
+    assert_type_eq::<<L as IntersectByOrder<mk_hlist!(), Equal>>::Output, mk_hlist!()>();
+    assert_type_eq::<<L as IntersectByOrder<mk_hlist!(), Greater>>::Output, mk_hlist!()>();
+    assert_type_eq::<<mk_hlist!() as IntersectByOrder<mk_hlist!(), Equal>>::Output, mk_hlist!()>();
+    assert_type_eq::<<mk_hlist!() as IntersectByOrder<L, Less>>::Output, mk_hlist!()>();
+    assert_type_eq::<<mk_hlist!() as IntersectByOrder<L, Equal>>::Output, mk_hlist!()>();
+    assert_type_eq::<<mk_hlist!() as IntersectByOrder<L, Greater>>::Output, mk_hlist!()>();
+
+    // the non-empty branches are unaffected
+    assert_type_eq::<<L as IntersectByOrder<mk_hlist!(U1, U2), Equal>>::Output, L>();
+    assert_type_eq::<<L as IntersectByOrder<mk_hlist!(U2), Less>>::Output, mk_hlist!(U2)>();
+}
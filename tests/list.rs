@@ -0,0 +1,261 @@
+use sorted_hlist::prelude::*;
+use sorted_hlist::SkipUpTo;
+use sorted_hlist::{
+    concat_hlists, AdjacentPairs, CartesianProduct, ChunkBy, Concat, Enumerate, ExactLen, Filter,
+    Flatten, GreaterThan, HList1, HList12, HList3, HListOf, Homogeneous, Keys, Len, MaxLen, MinLen,
+    OnValue, PowerSet, Repeat, SafeGet, Skip, SplitPrefix, Stride, TNone, TSome, Take, KV,
+};
+use typenum::{Unsigned, U0, U1, U10, U11, U12, U2, U3, U4, U5, U6, U7, U8, U9};
+
+#[test]
+fn concat_lists() {
+    type A = mk_hlist!(U1, U2);
+    type B = mk_hlist!(U3, U4, U5);
+    assert_type_eq::<<A as Concat<B>>::Output, mk_hlist!(U1, U2, U3, U4, U5)>();
+    assert_type_eq::<<mk_hlist!() as Concat<B>>::Output, B>();
+    assert_type_eq::<<A as Concat<mk_hlist!()>>::Output, A>();
+}
+
+#[test]
+fn cartesian_product_2x2() {
+    type A = mk_hlist!(u8, u16);
+    type B = mk_hlist!(i8, i16);
+    type Expected = mk_hlist!((u8, i8), (u8, i16), (u16, i8), (u16, i16));
+    assert_type_eq::<<A as CartesianProduct<B>>::Output, Expected>();
+}
+
+#[test]
+fn cartesian_product_2x3() {
+    type A = mk_hlist!(U1, U2);
+    type B = mk_hlist!(U3, U4, U5);
+    type Expected = mk_hlist!((U1, U3), (U1, U4), (U1, U5), (U2, U3), (U2, U4), (U2, U5));
+    assert_type_eq::<<A as CartesianProduct<B>>::Output, Expected>();
+}
+
+#[test]
+fn cartesian_product_with_empty() {
+    type A = mk_hlist!(U1, U2);
+    assert_type_eq::<<A as CartesianProduct<mk_hlist!()>>::Output, mk_hlist!()>();
+    assert_type_eq::<<mk_hlist!() as CartesianProduct<A>>::Output, mk_hlist!()>();
+}
+
+#[test]
+fn power_set_empty() {
+    assert_type_eq::<<mk_hlist!() as PowerSet>::Output, mk_hlist!(mk_hlist!())>();
+}
+
+#[test]
+fn power_set_single() {
+    type Expected = mk_hlist!(mk_hlist!(), mk_hlist!(U1));
+    assert_type_eq::<<mk_hlist!(U1) as PowerSet>::Output, Expected>();
+}
+
+#[test]
+fn power_set_three() {
+    type Expected = mk_hlist!(
+        mk_hlist!(),
+        mk_hlist!(U3),
+        mk_hlist!(U2),
+        mk_hlist!(U2, U3),
+        mk_hlist!(U1),
+        mk_hlist!(U1, U3),
+        mk_hlist!(U1, U2),
+        mk_hlist!(U1, U2, U3)
+    );
+    assert_type_eq::<<mk_hlist!(U1, U2, U3) as PowerSet>::Output, Expected>();
+}
+
+#[test]
+fn take_and_skip() {
+    type L = mk_hlist!(U1, U2, U3, U4);
+    assert_type_eq::<<L as Take<U0>>::Output, mk_hlist!()>();
+    assert_type_eq::<<L as Take<U2>>::Output, mk_hlist!(U1, U2)>();
+    assert_type_eq::<<L as Take<U4>>::Output, L>();
+    assert_type_eq::<<L as Skip<U0>>::Output, L>();
+    assert_type_eq::<<L as Skip<U3>>::Output, mk_hlist!(U4)>();
+    assert_type_eq::<<L as Skip<U4>>::Output, mk_hlist!()>();
+}
+
+#[test]
+fn chunk_by_two() {
+    type L = mk_hlist!(U1, U2, U3, U4, U5, U6);
+    type Expected = mk_hlist!(mk_hlist!(U1, U2), mk_hlist!(U3, U4), mk_hlist!(U5, U6));
+    assert_type_eq::<<L as ChunkBy<U2>>::Output, Expected>();
+}
+
+#[test]
+fn chunk_by_three() {
+    type L = mk_hlist!(U1, U2, U3, U4, U5, U6);
+    type Expected = mk_hlist!(mk_hlist!(U1, U2, U3), mk_hlist!(U4, U5, U6));
+    assert_type_eq::<<L as ChunkBy<U3>>::Output, Expected>();
+    assert_type_eq::<<mk_hlist!() as ChunkBy<U3>>::Output, mk_hlist!()>();
+}
+
+#[test]
+fn homogeneous_lists() {
+    assert_type_eq::<<mk_hlist!(u8) as Homogeneous>::Elem, u8>();
+    assert_type_eq::<<mk_hlist!(U1, U1, U1) as Homogeneous>::Elem, U1>();
+    assert_type_eq::<<mk_hlist!() as Homogeneous>::Elem, core::convert::Infallible>();
+}
+
+#[test]
+fn concat_hlists_macro() {
+    type A = mk_hlist!(U1);
+    type B = mk_hlist!(U2, U3);
+    type C = mk_hlist!();
+    type D = mk_hlist!(U4, U5, U6);
+    type Manual = <<<A as Concat<B>>::Output as Concat<C>>::Output as Concat<D>>::Output;
+    assert_type_eq::<concat_hlists!(A, B, C, D), Manual>();
+    assert_type_eq::<concat_hlists!(A, B, C, D), mk_hlist!(U1, U2, U3, U4, U5, U6)>();
+    assert_type_eq::<concat_hlists!(B), B>();
+}
+
+#[test]
+fn flatten_lists() {
+    type Nested = mk_hlist!(mk_hlist!(U1, U2), mk_hlist!(), mk_hlist!(U3));
+    assert_type_eq::<<Nested as Flatten>::Output, mk_hlist!(U1, U2, U3)>();
+    assert_type_eq::<<mk_hlist!() as Flatten>::Output, mk_hlist!()>();
+    assert_type_eq::<<mk_hlist!(mk_hlist!(), mk_hlist!()) as Flatten>::Output, mk_hlist!()>();
+}
+
+#[test]
+fn flatten_undoes_chunking() {
+    type L = mk_hlist!(U1, U2, U3, U4, U5, U6);
+    assert_type_eq::<<<L as ChunkBy<U2>>::Output as Flatten>::Output, L>();
+    assert_type_eq::<<<L as ChunkBy<U3>>::Output as Flatten>::Output, L>();
+}
+
+#[test]
+fn stride_lists() {
+    type L = mk_hlist!(U1, U2, U3, U4, U5, U6);
+    assert_type_eq::<<L as Stride<U1>>::Output, L>();
+    assert_type_eq::<<L as Stride<U2>>::Output, mk_hlist!(U1, U3, U5)>();
+    assert_type_eq::<<L as Stride<U3>>::Output, mk_hlist!(U1, U4)>();
+    assert_type_eq::<<mk_hlist!() as Stride<U2>>::Output, mk_hlist!()>();
+}
+
+#[test]
+fn stride_uneven_lengths() {
+    type L = mk_hlist!(U1, U2, U3, U4, U5);
+    assert_type_eq::<<L as Stride<U2>>::Output, mk_hlist!(U1, U3, U5)>();
+    assert_type_eq::<<L as Stride<U3>>::Output, mk_hlist!(U1, U4)>();
+    assert_type_eq::<<L as Stride<U4>>::Output, mk_hlist!(U1, U5)>();
+    assert_type_eq::<<mk_hlist!(U1, U2) as Stride<U3>>::Output, mk_hlist!(U1)>();
+    assert_type_eq::<<mk_hlist!(U7) as Stride<U3>>::Output, mk_hlist!(U7)>();
+}
+
+#[test]
+fn skip_up_to_saturates() {
+    type L = mk_hlist!(U1, U2, U3);
+    assert_type_eq::<<L as SkipUpTo<U2>>::Output, mk_hlist!(U3)>();
+    assert_type_eq::<<L as SkipUpTo<U3>>::Output, mk_hlist!()>();
+    assert_type_eq::<<L as SkipUpTo<U5>>::Output, mk_hlist!()>();
+}
+
+#[test]
+fn adjacent_pairs() {
+    type L = mk_hlist!(U1, U2, U3, U4);
+    assert_type_eq::<<L as AdjacentPairs>::Output, mk_hlist!((U1, U2), (U2, U3), (U3, U4))>();
+    assert_type_eq::<<mk_hlist!(U1) as AdjacentPairs>::Output, mk_hlist!()>();
+    assert_type_eq::<<mk_hlist!() as AdjacentPairs>::Output, mk_hlist!()>();
+}
+
+#[test]
+fn split_prefix() {
+    type L = mk_hlist!(U1, U2, U3);
+    assert_type_eq::<<L as SplitPrefix<mk_hlist!()>>::Suffix, L>();
+    assert_type_eq::<<L as SplitPrefix<mk_hlist!(U1, U2)>>::Suffix, mk_hlist!(U3)>();
+    assert_type_eq::<<L as SplitPrefix<L>>::Suffix, mk_hlist!()>();
+    assert_type_eq::<concat_hlists!(mk_hlist!(U1), <L as SplitPrefix<mk_hlist!(U1)>>::Suffix), L>();
+}
+
+const fn list_of<L: HListOf<E>, E>() {}
+
+#[test]
+fn hlist_of() {
+    list_of::<mk_hlist!(U1, U1, U1), U1>();
+    list_of::<mk_hlist!(), U1>();
+    list_of::<mk_hlist!(), u8>();
+    list_of::<mk_hlist!(mk_hlist!(), mk_hlist!()), mk_hlist!()>();
+}
+
+#[test]
+fn len() {
+    assert_type_eq::<<mk_hlist!() as Len>::Output, U0>();
+    assert_type_eq::<<mk_hlist!(u8, u8, u16) as Len>::Output, U3>();
+    assert_eq!(<mk_hlist!(U1, U2, U3, U4, U5, U6) as Len>::Output::USIZE, 6);
+}
+
+const fn min_len<L: MinLen<N>, N: Unsigned>() {}
+const fn max_len<L: MaxLen<N>, N: Unsigned>() {}
+const fn exact_len<L: ExactLen<N>, N: Unsigned>() {}
+
+#[test]
+fn length_bounds() {
+    type L = mk_hlist!(u8, u8, u8);
+    min_len::<L, U0>();
+    min_len::<L, U3>();
+    max_len::<L, U3>();
+    max_len::<L, U6>();
+    exact_len::<L, U3>();
+    exact_len::<mk_hlist!(), U0>();
+    max_len::<mk_hlist!(), U0>();
+    let _: AssertLen<L, U3> = AssertLen::new();
+}
+
+#[test]
+fn safe_get() {
+    type L = mk_hlist!(U1, U2, U3);
+    assert_type_eq::<<L as SafeGet<U0>>::Output, TSome<U1>>();
+    assert_type_eq::<<L as SafeGet<U2>>::Output, TSome<U3>>();
+    // exactly the length is out of range
+    assert_type_eq::<<L as SafeGet<U3>>::Output, TNone>();
+    assert_type_eq::<<L as SafeGet<U6>>::Output, TNone>();
+    assert_type_eq::<<mk_hlist!() as SafeGet<U0>>::Output, TNone>();
+}
+
+#[test]
+fn fixed_size_aliases() {
+    assert_type_eq::<HList1<u8>, HCons<u8, HNil>>();
+    assert_type_eq::<HList3<u8, bool, char>, mk_hlist!(u8, bool, char)>();
+    assert_type_eq::<
+        HList12<U1, U2, U3, U4, U5, U6, U7, U8, U9, U10, U11, U12>,
+        mk_hlist!(U1, U2, U3, U4, U5, U6, U7, U8, U9, U10, U11, U12),
+    >();
+}
+
+#[test]
+fn const_len() {
+    assert_eq!(<mk_hlist!(u8, bool, char) as HList>::LEN, 3);
+    assert_eq!(<mk_hlist!() as HList>::LEN, 0);
+    assert_eq!(
+        <mk_hlist!(U1, U2, U3, U4) as HList>::LEN,
+        <<mk_hlist!(U1, U2, U3, U4) as Len>::Output as Unsigned>::USIZE
+    );
+}
+
+#[test]
+fn repeat() {
+    assert_type_eq::<Repeat<U4, U0>, HNil>();
+    assert_type_eq::<Repeat<U4, U1>, mk_hlist!(U4)>();
+    assert_type_eq::<Repeat<U4, U8>, mk_hlist!(U4, U4, U4, U4, U4, U4, U4, U4)>();
+    const fn sorted<L: SortedHList>() {}
+    sorted::<Repeat<U4, U8>>();
+}
+
+#[test]
+fn enumerate() {
+    assert_type_eq::<<mk_hlist!() as Enumerate>::Output, mk_hlist!()>();
+    assert_type_eq::<<mk_hlist!(char) as Enumerate>::Output, mk_hlist!(KV<U0, char>)>();
+    assert_type_eq::<
+        <mk_hlist!(u8, bool, u8, char) as Enumerate>::Output,
+        mk_hlist!(KV<U0, u8>, KV<U1, bool>, KV<U2, u8>, KV<U3, char>),
+    >();
+}
+
+#[test]
+fn enumerate_filter_positions() {
+    type L = mk_hlist!(U9, U2, U5, U12, U1);
+    type Large = <<L as Enumerate>::Output as Filter<OnValue<GreaterThan<U4>>>>::Output;
+    assert_type_eq::<<Large as Keys>::Output, mk_hlist!(U0, U2, U3)>();
+}
@@ -0,0 +1,215 @@
+use sorted_hlist::prelude::*;
+use sorted_hlist::{GetValue, InsertEntry, JoinEntries, Keys, MergeEntries, TPair, Values, KV};
+use typenum::{U0, U1, U12, U16, U2, U20, U3, U4, U6, U8};
+
+struct Ctrl;
+struct Status;
+struct Data;
+struct Irq;
+
+const fn sorted<L: SortedHList>() {}
+
+#[test]
+fn map_sorted_by_key() {
+    // values carry no ordering, only the keys must be non-decreasing
+    sorted::<mk_hlist!(KV<U0, Data>, KV<U4, Ctrl>, KV<U8, Status>)>();
+    sorted::<mk_hlist!(KV<U1, Irq>, KV<U1, Ctrl>)>();
+}
+
+#[test]
+fn map_intersection_keeps_left_values() {
+    type Left = mk_hlist!(KV<U0, Ctrl>, KV<U4, Status>, KV<U8, Data>);
+    type Right = mk_hlist!(KV<U2, Irq>, KV<U4, u32>, KV<U8, u8>);
+    type Expected = mk_hlist!(KV<U4, Status>, KV<U8, Data>);
+    type Computed = <Left as Intersect<Right>>::Output;
+    assert_type_eq::<Computed, Expected>();
+}
+
+#[test]
+fn map_intersection_with_key_list() {
+    type Map = mk_hlist!(KV<U0, Ctrl>, KV<U4, Status>, KV<U8, Data>);
+    type Expected = mk_hlist!(KV<U0, Ctrl>, KV<U8, Data>);
+    type Computed = <Map as Intersect<mk_hlist!(U0, U2, U8)>>::Output;
+    assert_type_eq::<Computed, Expected>();
+}
+
+#[test]
+fn map_intersection_disjoint() {
+    type Left = mk_hlist!(KV<U0, Ctrl>, KV<U1, Status>);
+    type Right = mk_hlist!(KV<U2, Ctrl>, KV<U3, Status>);
+    assert_type_eq::<<Left as Intersect<Right>>::Output, mk_hlist!()>();
+}
+
+trait Register {
+    const WIDTH: u32;
+}
+impl Register for Ctrl {
+    const WIDTH: u32 = 8;
+}
+impl Register for Status {
+    const WIDTH: u32 = 16;
+}
+impl Register for Data {
+    const WIDTH: u32 = 32;
+}
+
+fn width_of<Map: GetValue<Offset>, Offset>() -> u32
+where
+    <Map as GetValue<Offset>>::Output: Register,
+{
+    <<Map as GetValue<Offset>>::Output as Register>::WIDTH
+}
+
+type Device = mk_hlist!(
+    KV<U0, Ctrl>,
+    KV<U4, Status>,
+    KV<U8, Data>,
+    KV<U12, Irq>,
+    KV<U16, Data>
+);
+
+#[test]
+fn get_value_first_middle_last() {
+    assert_type_eq::<<Device as GetValue<U0>>::Output, Ctrl>();
+    assert_type_eq::<<Device as GetValue<U8>>::Output, Data>();
+    assert_type_eq::<<Device as GetValue<U16>>::Output, Data>();
+}
+
+#[test]
+fn get_value_in_further_bound() {
+    assert_eq!(width_of::<Device, U0>(), 8);
+    assert_eq!(width_of::<Device, U4>(), 16);
+    assert_eq!(width_of::<Device, U16>(), 32);
+}
+
+type Small = mk_hlist!(KV<U4, Status>, KV<U8, Data>);
+
+#[test]
+fn insert_entry_before_first() {
+    type Computed = <Small as InsertEntry<U0, Ctrl>>::Output;
+    type Expected = mk_hlist!(KV<U0, Ctrl>, KV<U4, Status>, KV<U8, Data>);
+    assert_type_eq::<Computed, Expected>();
+    sorted::<Computed>();
+}
+
+#[test]
+fn insert_entry_between() {
+    type Computed = <Small as InsertEntry<U6, Irq>>::Output;
+    type Expected = mk_hlist!(KV<U4, Status>, KV<U6, Irq>, KV<U8, Data>);
+    assert_type_eq::<Computed, Expected>();
+    sorted::<Computed>();
+}
+
+#[test]
+fn insert_entry_after_last() {
+    type Computed = <Small as InsertEntry<U12, Irq>>::Output;
+    type Expected = mk_hlist!(KV<U4, Status>, KV<U8, Data>, KV<U12, Irq>);
+    assert_type_eq::<Computed, Expected>();
+    sorted::<Computed>();
+}
+
+#[test]
+fn insert_entry_into_empty() {
+    type Computed = <mk_hlist!() as InsertEntry<U4, Status>>::Output;
+    assert_type_eq::<Computed, mk_hlist!(KV<U4, Status>)>();
+}
+
+#[test]
+fn insert_entry_updates_existing_key() {
+    type Computed = <Small as InsertEntry<U4, Irq>>::Output;
+    type Expected = mk_hlist!(KV<U4, Irq>, KV<U8, Data>);
+    assert_type_eq::<Computed, Expected>();
+    assert_type_eq::<<Computed as GetValue<U4>>::Output, Irq>();
+    assert_type_eq::<<Computed as GetValue<U8>>::Output, Data>();
+}
+
+#[test]
+fn merge_entries_non_overlapping() {
+    type Defaults = mk_hlist!(KV<U0, Ctrl>, KV<U8, Data>);
+    type Overrides = mk_hlist!(KV<U4, Status>, KV<U12, Irq>);
+    type Computed = <Defaults as MergeEntries<Overrides>>::Output;
+    type Expected = mk_hlist!(KV<U0, Ctrl>, KV<U4, Status>, KV<U8, Data>, KV<U12, Irq>);
+    assert_type_eq::<Computed, Expected>();
+}
+
+#[test]
+fn merge_entries_full_overlap_is_other() {
+    type Defaults = mk_hlist!(KV<U0, Ctrl>, KV<U4, Status>);
+    type Overrides = mk_hlist!(KV<U0, u8>, KV<U4, u16>);
+    type Computed = <Defaults as MergeEntries<Overrides>>::Output;
+    assert_type_eq::<Computed, Overrides>();
+}
+
+#[test]
+fn merge_entries_partial_overlap_is_right_biased() {
+    type Defaults = mk_hlist!(KV<U0, Ctrl>, KV<U4, Status>, KV<U8, Data>);
+    type Overrides = mk_hlist!(KV<U4, u16>, KV<U12, u32>);
+    type Computed = <Defaults as MergeEntries<Overrides>>::Output;
+    sorted::<Computed>();
+    assert_type_eq::<<Computed as GetValue<U0>>::Output, Ctrl>();
+    assert_type_eq::<<Computed as GetValue<U4>>::Output, u16>();
+    assert_type_eq::<<Computed as GetValue<U8>>::Output, Data>();
+    assert_type_eq::<<Computed as GetValue<U12>>::Output, u32>();
+}
+
+#[test]
+fn merge_entries_with_empty() {
+    type Map = mk_hlist!(KV<U0, Ctrl>, KV<U4, Status>);
+    assert_type_eq::<<Map as MergeEntries<mk_hlist!()>>::Output, Map>();
+    assert_type_eq::<<mk_hlist!() as MergeEntries<Map>>::Output, Map>();
+}
+
+#[test]
+fn keys_and_values() {
+    assert_type_eq::<<Device as Keys>::Output, mk_hlist!(U0, U4, U8, U12, U16)>();
+    assert_type_eq::<<Device as Values>::Output, mk_hlist!(Ctrl, Status, Data, Irq, Data)>();
+    assert_type_eq::<<mk_hlist!() as Keys>::Output, mk_hlist!()>();
+    assert_type_eq::<<mk_hlist!() as Values>::Output, mk_hlist!()>();
+    sorted::<<Device as Keys>::Output>();
+}
+
+/// Look up every key of `Self` in `Map`.
+trait LookupAll<Map> {
+    type Out;
+}
+impl<Map> LookupAll<Map> for HNil {
+    type Out = HNil;
+}
+impl<Map: GetValue<H>, H, T: LookupAll<Map>> LookupAll<Map> for HCons<H, T> {
+    type Out = HCons<<Map as GetValue<H>>::Output, <T as LookupAll<Map>>::Out>;
+}
+
+#[test]
+fn keys_intersect_then_lookup() {
+    type Used = <<Device as Keys>::Output as Intersect<mk_hlist!(U2, U4, U12, U20)>>::Output;
+    assert_type_eq::<Used, mk_hlist!(U4, U12)>();
+    assert_type_eq::<<Used as LookupAll<Device>>::Out, mk_hlist!(Status, Irq)>();
+}
+
+#[test]
+fn join_entries_overlapping() {
+    type Producers = mk_hlist!(KV<U0, Ctrl>, KV<U4, Status>, KV<U8, Data>);
+    type Consumers = mk_hlist!(KV<U4, u16>, KV<U8, u32>, KV<U12, u8>);
+    type Computed = <Producers as JoinEntries<Consumers>>::Output;
+    type Expected = mk_hlist!(KV<U4, TPair<Status, u16>>, KV<U8, TPair<Data, u32>>);
+    assert_type_eq::<Computed, Expected>();
+    sorted::<Computed>();
+}
+
+#[test]
+fn join_entries_disjoint() {
+    type Left = mk_hlist!(KV<U0, Ctrl>, KV<U4, Status>);
+    type Right = mk_hlist!(KV<U8, Data>, KV<U12, Irq>);
+    assert_type_eq::<<Left as JoinEntries<Right>>::Output, mk_hlist!()>();
+    assert_type_eq::<<mk_hlist!() as JoinEntries<Right>>::Output, mk_hlist!()>();
+    assert_type_eq::<<Left as JoinEntries<mk_hlist!()>>::Output, mk_hlist!()>();
+}
+
+#[test]
+fn join_entries_identical_keys() {
+    type Left = mk_hlist!(KV<U0, Ctrl>, KV<U4, Status>);
+    type Right = mk_hlist!(KV<U0, u8>, KV<U4, u16>);
+    type Computed = <Left as JoinEntries<Right>>::Output;
+    type Expected = mk_hlist!(KV<U0, TPair<Ctrl, u8>>, KV<U4, TPair<Status, u16>>);
+    assert_type_eq::<Computed, Expected>();
+}
@@ -0,0 +1,50 @@
+use sorted_hlist::prelude::*;
+use sorted_hlist::{
+    InnerList, Len, LowerBound, Member, SafeGet, TNone, TSome, UpperBound, WithMeta,
+};
+use typenum::{U1, U2, U3, U4};
+
+struct Datasheet;
+
+type A = WithMeta<Datasheet, mk_hlist!(U1, U2, U3)>;
+type B = WithMeta<Datasheet, mk_hlist!(U2, U3, U4)>;
+
+const fn sorted<L: SortedHList>() {}
+
+#[test]
+fn with_meta_is_sorted() {
+    sorted::<A>();
+    assert_type_eq::<InnerList<A>, mk_hlist!(U1, U2, U3)>();
+}
+
+#[test]
+fn with_meta_intersect() {
+    type Computed = <A as Intersect<B>>::Output;
+    assert_type_eq::<Computed, WithMeta<Datasheet, mk_hlist!(U2, U3)>>();
+    assert_type_eq::<InnerList<Computed>, mk_hlist!(U2, U3)>();
+}
+
+#[test]
+fn with_meta_union_and_difference() {
+    assert_type_eq::<InnerList<<A as Union<B>>::Output>, mk_hlist!(U1, U2, U3, U4)>();
+    assert_type_eq::<InnerList<<A as Difference<B>>::Output>, mk_hlist!(U1)>();
+}
+
+#[test]
+fn with_meta_chains() {
+    type C = WithMeta<Datasheet, mk_hlist!(U3)>;
+    type Computed = <<A as Intersect<B>>::Output as Intersect<C>>::Output;
+    assert_type_eq::<InnerList<Computed>, mk_hlist!(U3)>();
+}
+
+#[test]
+fn with_meta_queries() {
+    assert_type_eq::<<A as Len>::Output, U3>();
+    assert_type_eq::<<A as SafeGet<U1>>::Output, TSome<U2>>();
+    assert_type_eq::<<A as SafeGet<U3>>::Output, TNone>();
+    assert_type_eq::<<A as LowerBound<U2>>::Output, U1>();
+    assert_type_eq::<<A as UpperBound<U3>>::Output, U3>();
+    const _: AssertLen<A, U3> = AssertLen::new();
+    fn member<L: Member<U2>>() {}
+    member::<A>();
+}
@@ -0,0 +1,85 @@
+use sorted_hlist::prelude::*;
+use sorted_hlist::{type_list_name, TypeName, TypeRepr};
+use typenum::{B1, N1000, N3, P7, U0, U1000, U4096, U64, U65, U7, Z0};
+
+struct CapRead;
+struct CapWrite;
+
+impl TypeName for CapRead {
+    const TYPE_NAME: &'static str = "CapRead";
+}
+
+impl TypeName for CapWrite {
+    const TYPE_NAME: &'static str = "CapWrite";
+}
+
+#[test]
+fn list_names() {
+    assert_eq!(format!("{}", type_list_name::<mk_hlist!()>()), "HNil");
+    assert_eq!(
+        format!("{}", type_list_name::<mk_hlist!(u8, bool)>()),
+        "HCons<u8, HCons<bool, HNil>>"
+    );
+    assert_eq!(
+        type_list_name::<mk_hlist!(CapRead, CapWrite, char)>().to_string(),
+        "HCons<CapRead, HCons<CapWrite, HCons<char, HNil>>>"
+    );
+}
+
+#[test]
+fn typenum_names() {
+    assert_eq!(
+        type_list_name::<HCons<u8, HNil>>().to_string(),
+        "HCons<u8, HNil>"
+    );
+    assert_eq!(
+        type_list_name::<mk_hlist!(U0, U7, U64)>().to_string(),
+        "HCons<U0, HCons<U7, HCons<U64, HNil>>>"
+    );
+    assert_eq!(<B1 as TypeName>::TYPE_NAME, "B1");
+}
+
+#[test]
+fn large_typenum_names() {
+    assert_eq!(<U65 as TypeName>::TYPE_NAME, "U65");
+    assert_eq!(<U1000 as TypeName>::TYPE_NAME, "U1000");
+    assert_eq!(
+        type_list_name::<mk_hlist!(U64, U4096)>().to_string(),
+        "HCons<U64, HCons<U4096, HNil>>"
+    );
+    type Max = typenum::Sub1<typenum::Shleft<typenum::U1, typenum::U64>>;
+    assert_eq!(<Max as TypeName>::TYPE_NAME, "U18446744073709551615");
+}
+
+#[test]
+fn signed_typenum_names() {
+    assert_eq!(<Z0 as TypeName>::TYPE_NAME, "Z0");
+    assert_eq!(<P7 as TypeName>::TYPE_NAME, "P7");
+    assert_eq!(<N3 as TypeName>::TYPE_NAME, "N3");
+    assert_eq!(<N1000 as TypeName>::TYPE_NAME, "N1000");
+    assert_eq!(
+        type_list_name::<mk_hlist!(N3, Z0, P7)>().to_string(),
+        "HCons<N3, HCons<Z0, HCons<P7, HNil>>>"
+    );
+}
+
+#[test]
+fn type_repr() {
+    const NAME: &str = <HCons<u8, HNil> as TypeRepr>::TYPE_REPR;
+    assert_eq!(NAME, "HCons<u8, HNil>");
+    assert_eq!(<HNil as TypeRepr>::TYPE_REPR, "HNil");
+    assert_eq!(<U7 as TypeRepr>::TYPE_REPR, "U7");
+    assert_eq!(
+        <mk_hlist!(CapRead, mk_hlist!(N3), U1000) as TypeRepr>::TYPE_REPR,
+        "HCons<CapRead, HCons<HCons<N3, HNil>, HCons<U1000, HNil>>>"
+    );
+}
+
+#[test]
+fn type_repr_matches_type_list_name() {
+    type L = mk_hlist!(CapWrite, U0, B1, char, P7);
+    assert_eq!(
+        <L as TypeRepr>::TYPE_REPR,
+        type_list_name::<L>().to_string()
+    );
+}
@@ -0,0 +1,26 @@
+use sorted_hlist::prelude::*;
+use typenum::{And, Diff, Or, U1, U3, U4, U5};
+
+type A = mk_hlist!(U1, U3, U5);
+type B = mk_hlist!(U3, U4, U5);
+
+#[test]
+fn operator_types() {
+    assert_type_eq::<And<A, B>, <A as Intersect<B>>::Output>();
+    assert_type_eq::<Or<A, B>, <A as Union<B>>::Output>();
+    assert_type_eq::<Diff<A, B>, <A as Difference<B>>::Output>();
+    assert_type_eq::<And<HNil, A>, HNil>();
+    assert_type_eq::<Diff<A, HNil>, A>();
+}
+
+#[test]
+fn operator_values() {
+    assert_eq!(A::new() & B::new(), <mk_hlist!(U3, U5)>::new());
+    assert_eq!(A::new() | B::new(), <mk_hlist!(U1, U3, U4, U5)>::new());
+    assert_eq!(A::new() - B::new(), <mk_hlist!(U1)>::new());
+    assert_eq!(
+        (A::new() | B::new()) - (A::new() & B::new()),
+        <mk_hlist!(U1, U4)>::new()
+    );
+    assert_eq!(HNil | A::new(), A::new());
+}
@@ -0,0 +1,41 @@
+use sorted_hlist::prelude::*;
+use sorted_hlist::{Len, ListPair, SortedHListPair};
+use typenum::{Unsigned, U1, U3, U4, U5};
+
+type A = mk_hlist!(U1, U3, U5);
+type B = mk_hlist!(U3, U4, U5);
+type P = SortedHListPair<A, B>;
+
+#[test]
+fn pair_members() {
+    assert_type_eq::<<P as ListPair>::Left, A>();
+    assert_type_eq::<<P as ListPair>::Right, B>();
+    assert_type_eq::<<P as ListPair>::Intersection, mk_hlist!(U3, U5)>();
+    assert_type_eq::<<P as ListPair>::Union, mk_hlist!(U1, U3, U4, U5)>();
+}
+
+#[test]
+fn pair_as_one_parameter() {
+    fn overlap<Pair: ListPair>() -> usize
+    where
+        Pair::Intersection: Len,
+    {
+        <<Pair::Intersection as Len>::Output as Unsigned>::USIZE
+    }
+    assert_eq!(overlap::<P>(), 2);
+    assert_eq!(overlap::<SortedHListPair<A, mk_hlist!()>>(), 0);
+}
+
+#[test]
+fn pair_results_are_sorted() {
+    fn sorted_results<Pair: ListPair>() -> (usize, usize) {
+        (
+            <Pair::Intersection as HList>::LEN,
+            <Pair::Union as HList>::LEN,
+        )
+    }
+    const fn sorted<L: SortedHList>() {}
+    sorted::<<P as ListPair>::Intersection>();
+    sorted::<<P as ListPair>::Union>();
+    assert_eq!(sorted_results::<P>(), (2, 4));
+}
@@ -0,0 +1,49 @@
+use core::marker::PhantomData;
+use sorted_hlist::prelude::*;
+use sorted_hlist::{apply_patch, diff_patch, ApplyPatch, DiffPatch, Patch};
+use typenum::{U0, U1, U2, U3, U5, U7, U8, U9};
+
+type V1 = mk_hlist!(U1, U3, U5, U7);
+type V2 = mk_hlist!(U2, U3, U7, U8);
+
+#[test]
+fn diff_patch_parts() {
+    type Change = DiffPatch<V1, V2>;
+    assert_type_eq::<<Change as Patch>::Added, mk_hlist!(U2, U8)>();
+    assert_type_eq::<<Change as Patch>::Removed, mk_hlist!(U1, U5)>();
+
+    type Same = DiffPatch<V1, V1>;
+    assert_type_eq::<<Same as Patch>::Added, mk_hlist!()>();
+    assert_type_eq::<<Same as Patch>::Removed, mk_hlist!()>();
+}
+
+#[test]
+fn apply_patch_round_trips() {
+    assert_type_eq::<<V1 as ApplyPatch<DiffPatch<V1, V2>>>::Output, V2>();
+    assert_type_eq::<<V2 as ApplyPatch<DiffPatch<V2, V1>>>::Output, V1>();
+    let v1 = is_sorted_witness::<V1>();
+    let v2 = is_sorted_witness::<V2>();
+    let _: PhantomData<V2> = apply_patch(v1, diff_patch(v1, v2));
+    let _: PhantomData<V1> = apply_patch(v2, diff_patch(v2, v1));
+}
+
+#[test]
+fn apply_patch_to_other_lists() {
+    type Change = DiffPatch<mk_hlist!(U1, U2), mk_hlist!(U2)>;
+    assert_type_eq::<<mk_hlist!(U1, U9) as ApplyPatch<Change>>::Output, mk_hlist!(U9)>();
+    let w = apply_patch(
+        is_sorted_witness::<mk_hlist!(U0, U1)>(),
+        PhantomData::<Change>,
+    );
+    let _: PhantomData<mk_hlist!(U0)> = w;
+}
+
+#[test]
+fn patch_parts_are_sorted() {
+    fn sorted<L: SortedHList>() {}
+    fn parts<P: Patch>() {
+        sorted::<P::Added>();
+        sorted::<P::Removed>();
+    }
+    parts::<DiffPatch<V1, V2>>();
+}
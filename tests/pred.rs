@@ -0,0 +1,81 @@
+use sorted_hlist::prelude::*;
+use sorted_hlist::{
+    All, Any, CountWhere, Filter, Find, GreaterThan, IsEven, Len, LessThan, TNone, TSome,
+};
+use typenum::{Bit, Unsigned, B0, B1, U0, U1, U12, U2, U3, U31, U32, U4, U5, U6, U7, U9};
+
+const fn sorted<L: SortedHList>() {}
+
+#[test]
+fn filter_all_pass() {
+    type L = mk_hlist!(U2, U4, U6);
+    assert_type_eq::<<L as Filter<IsEven>>::Output, L>();
+    assert_type_eq::<<L as Filter<GreaterThan<U1>>>::Output, L>();
+}
+
+#[test]
+fn filter_none_pass() {
+    type L = mk_hlist!(U1, U3, U5);
+    assert_type_eq::<<L as Filter<IsEven>>::Output, mk_hlist!()>();
+    assert_type_eq::<<L as Filter<GreaterThan<U5>>>::Output, mk_hlist!()>();
+    assert_type_eq::<<mk_hlist!() as Filter<IsEven>>::Output, mk_hlist!()>();
+}
+
+#[test]
+fn filter_alternating() {
+    type L = mk_hlist!(U1, U2, U3, U4, U5, U6);
+    assert_type_eq::<<L as Filter<IsEven>>::Output, mk_hlist!(U2, U4, U6)>();
+    assert_type_eq::<<L as Filter<GreaterThan<U3>>>::Output, mk_hlist!(U4, U5, U6)>();
+    sorted::<<L as Filter<IsEven>>::Output>();
+}
+
+#[test]
+fn all_and_any_on_empty() {
+    assert_type_eq::<<mk_hlist!() as All<IsEven>>::Output, B1>();
+    assert_type_eq::<<mk_hlist!() as Any<IsEven>>::Output, B0>();
+}
+
+#[test]
+fn all_with_one_failure() {
+    assert_type_eq::<<mk_hlist!(U2, U4, U6) as All<IsEven>>::Output, B1>();
+    assert_type_eq::<<mk_hlist!(U2, U5, U6) as All<IsEven>>::Output, B0>();
+    assert_type_eq::<<mk_hlist!(U3, U32) as All<LessThan<U32>>>::Output, B0>();
+}
+
+#[test]
+fn any_with_one_success() {
+    assert_type_eq::<<mk_hlist!(U1, U3, U5) as Any<IsEven>>::Output, B0>();
+    assert_type_eq::<<mk_hlist!(U1, U4, U5) as Any<IsEven>>::Output, B1>();
+    assert_type_eq::<<mk_hlist!(U1, U2, U7) as Any<GreaterThan<U6>>>::Output, B1>();
+}
+
+const _: () = assert!(<mk_hlist!(U0, U12, U31) as All<LessThan<U32>>>::Output::BOOL);
+const _: () = assert!(!<mk_hlist!(U0, U12, U31) as Any<GreaterThan<U31>>>::Output::BOOL);
+
+#[test]
+fn count_where() {
+    type L = mk_hlist!(U1, U2, U3, U4, U6);
+    assert_type_eq::<<mk_hlist!(U1, U3) as CountWhere<IsEven>>::Output, U0>();
+    assert_type_eq::<<mk_hlist!(U2, U4) as CountWhere<IsEven>>::Output, U2>();
+    assert_type_eq::<<L as CountWhere<IsEven>>::Output, U3>();
+    assert_type_eq::<
+        <L as CountWhere<IsEven>>::Output,
+        <<L as Filter<IsEven>>::Output as Len>::Output,
+    >();
+    assert_type_eq::<<mk_hlist!() as CountWhere<IsEven>>::Output, U0>();
+}
+
+#[test]
+fn count_sizes_an_array() {
+    type Big = <mk_hlist!(U1, U5, U9, U12) as CountWhere<GreaterThan<U4>>>::Output;
+    let slots = [0u32; Big::USIZE];
+    assert_eq!(slots.len(), 3);
+}
+
+#[test]
+fn find_first_match() {
+    assert_type_eq::<<mk_hlist!(U2, U3, U4) as Find<IsEven>>::Output, TSome<U2>>();
+    assert_type_eq::<<mk_hlist!(U1, U3, U4) as Find<IsEven>>::Output, TSome<U4>>();
+    assert_type_eq::<<mk_hlist!(U1, U3, U5) as Find<IsEven>>::Output, TNone>();
+    assert_type_eq::<<mk_hlist!() as Find<IsEven>>::Output, TNone>();
+}
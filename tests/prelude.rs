@@ -0,0 +1,72 @@
+//! The prelude covers the core API, stays out of the way of other glob
+//! imports, and the exported macros also work through `#[macro_use]`.
+
+#[macro_use]
+extern crate sorted_hlist;
+
+use sorted_hlist::assert_type_eq;
+
+mod glob {
+    use sorted_hlist::prelude::*;
+    use sorted_hlist::{concat_hlists, intersect_all, sorted, union_all, IsDisjoint, LowerBound};
+    use typenum::{Unsigned, B1, U1, U2, U3, U4, U5};
+
+    #[test]
+    fn prelude_covers_common_use() {
+        type A = mk_hlist!(U1, U2, U3);
+        type B = mk_hlist!(U2, U3, U4);
+        assert_type_eq::<<A as Intersect<B>>::Output, mk_hlist!(U2, U3)>();
+        assert_type_eq::<<A as IntersectUnchecked<B>>::Output, mk_hlist!(U2, U3)>();
+        assert_type_eq::<<A as Union<B>>::Output, mk_hlist!(U1, U2, U3, U4)>();
+        assert_type_eq::<<A as Difference<B>>::Output, mk_hlist!(U1)>();
+        fn non_empty<L: NonEmptyHList>() {}
+        non_empty::<A>();
+        assert_type_eq::<intersect_all!(A, B, mk_hlist!(U3)), mk_hlist!(U3)>();
+        assert_type_eq::<union_all!(A, B), mk_hlist!(U1, U2, U3, U4)>();
+        assert_type_eq::<concat_hlists!(A, B), mk_hlist!(U1, U2, U3, U2, U3, U4)>();
+        assert_type_eq::<<A as IsDisjoint<mk_hlist!(U5)>>::Output, B1>();
+        assert_eq!(<A as LowerBound<U2>>::Output::USIZE, 1);
+        let _ = sorted::<A>();
+    }
+
+    #[test]
+    fn prelude_witnesses() {
+        type A = mk_hlist!(U1, U2, U3);
+        type B = mk_hlist!(U2, U3, U4);
+        fn expect<Expected, L: TypeEq<Expected>>(_: core::marker::PhantomData<L>) {}
+        expect::<mk_hlist!(U2, U3), _>(intersect::<A, B>());
+        expect::<mk_hlist!(U1, U2, U3, U4), _>(union::<A, B>());
+        expect::<mk_hlist!(U1), _>(difference::<A, B>());
+        let _ = is_sorted_witness::<A>();
+        assert_sorted::<A>();
+        let _ = AssertSorted::<A>::new();
+        let _: AssertLen<A, U3> = AssertLen::new();
+    }
+}
+
+mod glob_with_typenum {
+    use sorted_hlist::prelude::*;
+    use typenum::*;
+
+    #[test]
+    fn no_clash_with_typenum_glob() {
+        type A = mk_hlist!(U1, U2, U3);
+        type Common = <A as Intersect<mk_hlist!(U2)>>::Output;
+        assert_type_eq::<Common, mk_hlist!(U2)>();
+        assert_type_eq::<<A as Union<mk_hlist!(U4)>>::Output, mk_hlist!(U1, U2, U3, U4)>();
+        assert_type_eq::<<A as Difference<mk_hlist!(U2)>>::Output, mk_hlist!(U1, U3)>();
+        assert_type_eq::<Length<U8>, U4>();
+        assert_eq!(<Length<U8> as Unsigned>::USIZE, 4);
+        fn len<L: Len>() {}
+        len::<U1>();
+    }
+}
+
+#[test]
+fn macro_use_paths() {
+    type A = mk_hlist!(typenum::U1, typenum::U2);
+    type B = mk_hlist!(typenum::U2);
+    assert_type_eq::<intersect_all!(A, B), mk_hlist!(typenum::U2)>();
+    assert_type_eq::<union_all!(A, B), A>();
+    assert_type_eq::<concat_hlists!(B, B), mk_hlist!(typenum::U2, typenum::U2)>();
+}
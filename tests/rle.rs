@@ -0,0 +1,145 @@
+use sorted_hlist::prelude::*;
+use sorted_hlist::{
+    Flatten, GroupConsecutive, HCons, HList, HNil, Keys, Len, PrependRun, Repeat, RunLengthDecode,
+    RunLengthEncode, KV,
+};
+use typenum::{Unsigned, U0, U1, U2, U3, U4, U5, U6, U7};
+
+const fn sorted<L: SortedHList>() {}
+
+#[test]
+fn encode_without_duplicates() {
+    type L = mk_hlist!(U1, U2, U3);
+    assert_type_eq::<<L as RunLengthEncode>::Output, mk_hlist!(KV<U1, U1>, KV<U2, U1>, KV<U3, U1>)>(
+    );
+}
+
+#[test]
+fn encode_single_run() {
+    type L = mk_hlist!(U4, U4, U4, U4, U4);
+    assert_type_eq::<<L as RunLengthEncode>::Output, mk_hlist!(KV<U4, U5>)>();
+}
+
+#[test]
+fn encode_mixed_runs() {
+    type L = mk_hlist!(U1, U1, U2, U3, U3, U3);
+    type Runs = <L as RunLengthEncode>::Output;
+    assert_type_eq::<Runs, mk_hlist!(KV<U1, U2>, KV<U2, U1>, KV<U3, U3>)>();
+    assert_type_eq::<<Runs as Keys>::Output, mk_hlist!(U1, U2, U3)>();
+    sorted::<Runs>();
+}
+
+#[test]
+fn encode_empty() {
+    assert_type_eq::<<mk_hlist!() as RunLengthEncode>::Output, mk_hlist!()>();
+}
+
+#[test]
+fn decode_runs() {
+    type Runs = mk_hlist!(KV<U1, U2>, KV<U2, U1>, KV<U3, U3>);
+    assert_type_eq::<<Runs as RunLengthDecode>::Output, mk_hlist!(U1, U1, U2, U3, U3, U3)>();
+    sorted::<<Runs as RunLengthDecode>::Output>();
+    assert_type_eq::<<mk_hlist!() as RunLengthDecode>::Output, mk_hlist!()>();
+}
+
+#[test]
+fn decode_zero_count() {
+    type Runs = mk_hlist!(KV<U1, U0>, KV<U2, U2>, KV<U3, U0>);
+    assert_type_eq::<<Runs as RunLengthDecode>::Output, mk_hlist!(U2, U2)>();
+}
+
+#[test]
+fn encode_decode_round_trip() {
+    type L = mk_hlist!(U1, U1, U2, U3, U3, U3, U5);
+    assert_type_eq::<<<L as RunLengthEncode>::Output as RunLengthDecode>::Output, L>();
+
+    type Runs = mk_hlist!(KV<U2, U3>, KV<U4, U1>, KV<U6, U2>);
+    assert_type_eq::<<<Runs as RunLengthDecode>::Output as RunLengthEncode>::Output, Runs>();
+}
+
+#[test]
+fn group_all_same() {
+    type L = mk_hlist!(U3, U3, U3);
+    assert_type_eq::<<L as GroupConsecutive>::Output, mk_hlist!(mk_hlist!(U3, U3, U3))>();
+}
+
+#[test]
+fn group_all_different() {
+    type L = mk_hlist!(U1, U3, U2);
+    assert_type_eq::<
+        <L as GroupConsecutive>::Output,
+        mk_hlist!(mk_hlist!(U1), mk_hlist!(U3), mk_hlist!(U2)),
+    >();
+    assert_type_eq::<<mk_hlist!() as GroupConsecutive>::Output, mk_hlist!()>();
+}
+
+#[test]
+fn group_mixed_runs() {
+    type L = mk_hlist!(U1, U1, U2, U4, U4, U1);
+    type Groups = <L as GroupConsecutive>::Output;
+    assert_type_eq::<
+        Groups,
+        mk_hlist!(
+            mk_hlist!(U1, U1),
+            mk_hlist!(U2),
+            mk_hlist!(U4, U4),
+            mk_hlist!(U1)
+        ),
+    >();
+    assert_type_eq::<<Groups as Flatten>::Output, L>();
+}
+
+#[test]
+fn encode_unsorted_runs() {
+    type L = mk_hlist!(U3, U3, U1, U2, U2, U1);
+    assert_type_eq::<
+        <L as RunLengthEncode>::Output,
+        mk_hlist!(KV<U3, U2>, KV<U1, U1>, KV<U2, U2>, KV<U1, U1>),
+    >();
+}
+
+#[test]
+fn round_trip_several_inputs() {
+    type A = mk_hlist!();
+    type B = mk_hlist!(U7);
+    type C = mk_hlist!(U1, U1, U1, U1, U1, U1, U1, U1, U1);
+    type D = mk_hlist!(U5, U4, U4, U5, U0, U0);
+    assert_type_eq::<<<A as RunLengthEncode>::Output as RunLengthDecode>::Output, A>();
+    assert_type_eq::<<<B as RunLengthEncode>::Output as RunLengthDecode>::Output, B>();
+    assert_type_eq::<<<C as RunLengthEncode>::Output as RunLengthDecode>::Output, C>();
+    assert_type_eq::<<<D as RunLengthEncode>::Output as RunLengthDecode>::Output, D>();
+    // one run per group
+    assert_type_eq::<
+        <<D as RunLengthEncode>::Output as Len>::Output,
+        <<D as GroupConsecutive>::Output as Len>::Output,
+    >();
+}
+
+/// Each run of an encoding expanded into its own group.
+trait RunsAsGroups: HList {
+    type Output: HList;
+}
+
+impl RunsAsGroups for HNil {
+    type Output = HNil;
+}
+
+impl<K, C: Unsigned, R: RunsAsGroups> RunsAsGroups for HCons<KV<K, C>, R>
+where
+    HNil: PrependRun<K, C>,
+{
+    type Output = HCons<Repeat<K, C>, <R as RunsAsGroups>::Output>;
+}
+
+type EncodedGroups<L> = <<L as RunLengthEncode>::Output as RunsAsGroups>::Output;
+
+#[test]
+fn encode_agrees_with_group_consecutive() {
+    type A = mk_hlist!(U1, U1, U2, U3, U3, U3);
+    type B = mk_hlist!(U3, U3, U1, U2, U2, U1);
+    type C = mk_hlist!(U4, U4, U4, U4);
+    assert_type_eq::<EncodedGroups<A>, <A as GroupConsecutive>::Output>();
+    assert_type_eq::<EncodedGroups<B>, <B as GroupConsecutive>::Output>();
+    assert_type_eq::<EncodedGroups<C>, <C as GroupConsecutive>::Output>();
+    assert_type_eq::<EncodedGroups<mk_hlist!()>, <mk_hlist!() as GroupConsecutive>::Output>();
+}
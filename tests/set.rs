@@ -0,0 +1,307 @@
+use sorted_hlist::prelude::*;
+use sorted_hlist::{
+    intersect_all, union_all, AllDistinct, Complement, Deduplicate, DifferenceUnchecked,
+    Duplicates, HListEq, IntersectLen, IntersectWith, IsDisjoint, Len, Member, MultisetEq,
+    PairwiseDisjoint, SetEq, TotalCover, TypeBinaryFn, UnionAll, UnionUnchecked, VotingIntersect,
+    KV,
+};
+use typenum::{B0, B1, U0, U1, U2, U3, U4, U5, U6, U7, U9};
+
+#[test]
+fn is_disjoint() {
+    assert_type_eq::<<mk_hlist!(U1, U3, U5) as IsDisjoint<mk_hlist!(U2, U4, U6)>>::Output, B1>();
+    assert_type_eq::<<mk_hlist!(U1, U3, U5) as IsDisjoint<mk_hlist!(U2, U5)>>::Output, B0>();
+    assert_type_eq::<<mk_hlist!() as IsDisjoint<mk_hlist!(U1)>>::Output, B1>();
+    assert_type_eq::<<mk_hlist!(U1) as IsDisjoint<mk_hlist!()>>::Output, B1>();
+}
+
+#[test]
+fn pairwise_disjoint_two_disjoint() {
+    type Sets = mk_hlist!(mk_hlist!(U1, U2), mk_hlist!(U3, U4));
+    assert_type_eq::<<Sets as PairwiseDisjoint>::Output, B1>();
+}
+
+#[test]
+fn pairwise_disjoint_two_overlapping() {
+    type Sets = mk_hlist!(mk_hlist!(U1, U2, U3), mk_hlist!(U3, U4));
+    assert_type_eq::<<Sets as PairwiseDisjoint>::Output, B0>();
+}
+
+#[test]
+fn pairwise_disjoint_three() {
+    type Disjoint = mk_hlist!(mk_hlist!(U1, U4), mk_hlist!(U2, U5), mk_hlist!(U3, U6));
+    assert_type_eq::<<Disjoint as PairwiseDisjoint>::Output, B1>();
+
+    // only the first and last sets overlap
+    type Overlapping = mk_hlist!(mk_hlist!(U1, U4), mk_hlist!(U2, U5), mk_hlist!(U4, U6));
+    assert_type_eq::<<Overlapping as PairwiseDisjoint>::Output, B0>();
+}
+
+#[test]
+fn pairwise_disjoint_trivial() {
+    assert_type_eq::<<mk_hlist!() as PairwiseDisjoint>::Output, B1>();
+    assert_type_eq::<<mk_hlist!(mk_hlist!(U1, U2)) as PairwiseDisjoint>::Output, B1>();
+}
+
+const fn sorted<L: SortedHList>() {}
+
+#[test]
+fn union_two_lists() {
+    type A = mk_hlist!(U1, U3, U5);
+    type B = mk_hlist!(U2, U3, U6);
+    type Computed = <A as Union<B>>::Output;
+    assert_type_eq::<Computed, mk_hlist!(U1, U2, U3, U5, U6)>();
+    sorted::<Computed>();
+    assert_type_eq::<<mk_hlist!() as Union<B>>::Output, B>();
+    assert_type_eq::<<A as Union<mk_hlist!()>>::Output, A>();
+}
+
+#[test]
+fn union_output_is_sorted() {
+    fn union_sorted<A: Union<B>, B: SortedHList>() {
+        sorted::<<A as Union<B>>::Output>();
+    }
+    union_sorted::<mk_hlist!(U1, U4), mk_hlist!(U2, U4)>();
+}
+
+#[test]
+fn union_unchecked_skips_the_check() {
+    type Computed = <mk_hlist!(U3, U1) as UnionUnchecked<mk_hlist!(U2)>>::Output;
+    assert_type_eq::<Computed, mk_hlist!(U2, U3, U1)>();
+}
+
+#[test]
+fn union_all() {
+    type Sets = mk_hlist!(mk_hlist!(U4), mk_hlist!(U1, U2), mk_hlist!(U2, U6));
+    assert_type_eq::<<Sets as UnionAll>::Output, mk_hlist!(U1, U2, U4, U6)>();
+    assert_type_eq::<<mk_hlist!() as UnionAll>::Output, mk_hlist!()>();
+}
+
+#[test]
+fn hlist_eq() {
+    assert_type_eq::<<mk_hlist!(U1, U2) as HListEq<mk_hlist!(U1, U2)>>::Output, B1>();
+    assert_type_eq::<<mk_hlist!(U1, U2) as HListEq<mk_hlist!(U1, U3)>>::Output, B0>();
+    assert_type_eq::<<mk_hlist!(U1, U2) as HListEq<mk_hlist!(U1)>>::Output, B0>();
+    assert_type_eq::<<mk_hlist!() as HListEq<mk_hlist!(U1)>>::Output, B0>();
+    assert_type_eq::<<mk_hlist!() as HListEq<mk_hlist!()>>::Output, B1>();
+}
+
+#[test]
+fn total_cover_partition() {
+    type Universe = mk_hlist!(U1, U2, U3, U4, U5, U6);
+    type Parts = mk_hlist!(mk_hlist!(U1, U4), mk_hlist!(U2, U5, U6), mk_hlist!(U3));
+    assert_type_eq::<<Parts as TotalCover<Universe>>::Output, B1>();
+    assert_type_eq::<<Parts as PairwiseDisjoint>::Output, B1>();
+}
+
+#[test]
+fn total_cover_missing_or_extra() {
+    type Universe = mk_hlist!(U1, U2, U3, U4);
+    type Missing = mk_hlist!(mk_hlist!(U1, U4), mk_hlist!(U2));
+    type Extra = mk_hlist!(mk_hlist!(U1, U2), mk_hlist!(U3, U4, U5));
+    assert_type_eq::<<Missing as TotalCover<Universe>>::Output, B0>();
+    assert_type_eq::<<Extra as TotalCover<Universe>>::Output, B0>();
+}
+
+#[test]
+fn difference() {
+    type A = mk_hlist!(U1, U2, U3, U5);
+    type B = mk_hlist!(U2, U4, U5);
+    type Computed = <A as Difference<B>>::Output;
+    assert_type_eq::<Computed, mk_hlist!(U1, U3)>();
+    sorted::<Computed>();
+    assert_type_eq::<<B as Difference<A>>::Output, mk_hlist!(U4)>();
+    assert_type_eq::<<A as Difference<mk_hlist!()>>::Output, A>();
+    assert_type_eq::<<mk_hlist!() as Difference<A>>::Output, mk_hlist!()>();
+    assert_type_eq::<<A as Difference<A>>::Output, mk_hlist!()>();
+}
+
+#[test]
+fn difference_output_is_sorted() {
+    fn difference_sorted<A: Difference<B>, B: SortedHList>() {
+        sorted::<<A as Difference<B>>::Output>();
+    }
+    difference_sorted::<mk_hlist!(U1, U4), mk_hlist!(U2, U4)>();
+}
+
+#[test]
+fn difference_unchecked_skips_the_check() {
+    type Computed = <mk_hlist!(U3, U1) as DifferenceUnchecked<mk_hlist!(U3)>>::Output;
+    assert_type_eq::<Computed, mk_hlist!(U1)>();
+}
+
+#[test]
+fn union_all_macro() {
+    type A = mk_hlist!(U1, U4);
+    type B = mk_hlist!(U2);
+    type C = mk_hlist!(U4, U5);
+    type D = mk_hlist!(U1, U3, U6);
+    assert_type_eq::<union_all!(A, B, C, D), mk_hlist!(U1, U2, U3, U4, U5, U6)>();
+}
+
+const fn member<L: Member<X>, X>() {}
+
+#[test]
+fn member_positions() {
+    type L = mk_hlist!(U1, U3, U5);
+    member::<L, U1>();
+    member::<L, U3>();
+    member::<L, U5>();
+    member::<mk_hlist!(U2, U2), U2>();
+}
+
+#[test]
+fn member_of_intersection() {
+    type A = mk_hlist!(U1, U2, U4, U6);
+    type B = mk_hlist!(U2, U3, U4);
+    member::<<A as Intersect<B>>::Output, U2>();
+    member::<<A as Intersect<B>>::Output, U4>();
+    member::<<A as Union<B>>::Output, U3>();
+}
+
+#[test]
+fn all_distinct_and_duplicates() {
+    // clean
+    type Clean = mk_hlist!(U1, U2, U3);
+    assert_type_eq::<<Clean as AllDistinct>::Output, B1>();
+    assert_type_eq::<<Clean as Duplicates>::Output, mk_hlist!()>();
+    assert_type_eq::<<mk_hlist!() as AllDistinct>::Output, B1>();
+
+    // one pair
+    type Pair = mk_hlist!(U1, U2, U2, U3);
+    assert_type_eq::<<Pair as AllDistinct>::Output, B0>();
+    assert_type_eq::<<Pair as Duplicates>::Output, mk_hlist!(U2)>();
+
+    // a triple is listed once
+    type Triple = mk_hlist!(U1, U2, U2, U2, U3);
+    assert_type_eq::<<Triple as AllDistinct>::Output, B0>();
+    assert_type_eq::<<Triple as Duplicates>::Output, mk_hlist!(U2)>();
+
+    // at both ends
+    type Ends = mk_hlist!(U1, U1, U2, U3, U3);
+    assert_type_eq::<<Ends as AllDistinct>::Output, B0>();
+    assert_type_eq::<<Ends as Duplicates>::Output, mk_hlist!(U1, U3)>();
+}
+
+#[test]
+fn voting_intersect() {
+    type Backends = mk_hlist!(
+        mk_hlist!(U1, U2, U3, U5),
+        mk_hlist!(U2, U3, U5),
+        mk_hlist!(U3, U4, U5),
+        mk_hlist!(U1, U3, U6)
+    );
+    // every set -> intersection
+    assert_type_eq::<
+        <Backends as VotingIntersect<U4>>::Output,
+        intersect_all!(
+            mk_hlist!(U1, U2, U3, U5),
+            mk_hlist!(U2, U3, U5),
+            mk_hlist!(U3, U4, U5),
+            mk_hlist!(U1, U3, U6),
+        ),
+    >();
+    assert_type_eq::<<Backends as VotingIntersect<U4>>::Output, mk_hlist!(U3)>();
+    // a single vote -> union
+    assert_type_eq::<<Backends as VotingIntersect<U1>>::Output, <Backends as UnionAll>::Output>();
+    assert_type_eq::<<Backends as VotingIntersect<U1>>::Output, mk_hlist!(U1, U2, U3, U4, U5, U6)>(
+    );
+    assert_type_eq::<<Backends as VotingIntersect<U3>>::Output, mk_hlist!(U3, U5)>();
+    assert_type_eq::<<Backends as VotingIntersect<U2>>::Output, mk_hlist!(U1, U2, U3, U5)>();
+}
+
+#[test]
+fn voting_intersect_counts_each_set_once() {
+    type Sets = mk_hlist!(mk_hlist!(U1, U2, U2), mk_hlist!(U1));
+    assert_type_eq::<<Sets as VotingIntersect<U2>>::Output, mk_hlist!(U1)>();
+    assert_type_eq::<<Sets as VotingIntersect<U1>>::Output, mk_hlist!(U1, U2)>();
+}
+
+#[test]
+fn intersect_len_matches_intersect() {
+    type A = mk_hlist!(U1, U3, U5, U7);
+    type B = mk_hlist!(U2, U3, U4, U7, U9);
+    type C = mk_hlist!(U2, U4, U6);
+    assert_type_eq::<<A as IntersectLen<B>>::Output, <<A as Intersect<B>>::Output as Len>::Output>(
+    );
+    assert_type_eq::<<A as IntersectLen<B>>::Output, U2>();
+    // disjoint
+    assert_type_eq::<<A as IntersectLen<C>>::Output, <<A as Intersect<C>>::Output as Len>::Output>(
+    );
+    assert_type_eq::<<A as IntersectLen<C>>::Output, U0>();
+    // identical
+    assert_type_eq::<<B as IntersectLen<B>>::Output, <<B as Intersect<B>>::Output as Len>::Output>(
+    );
+    assert_type_eq::<<B as IntersectLen<B>>::Output, U5>();
+    // empty
+    assert_type_eq::<<mk_hlist!() as IntersectLen<A>>::Output, U0>();
+}
+
+#[test]
+fn set_multiset_and_structural_equality() {
+    type A = mk_hlist!(U1, U2, U2);
+    type B = mk_hlist!(U1, U2);
+    assert_type_eq::<<A as SetEq<B>>::Output, B1>();
+    assert_type_eq::<<A as MultisetEq<B>>::Output, B0>();
+    assert_type_eq::<<A as HListEq<B>>::Output, B0>();
+
+    // order only matters structurally
+    type C = mk_hlist!(U2, U1, U2);
+    assert_type_eq::<<A as SetEq<C>>::Output, B1>();
+    assert_type_eq::<<A as MultisetEq<C>>::Output, B1>();
+    assert_type_eq::<<A as HListEq<C>>::Output, B0>();
+
+    assert_type_eq::<<B as SetEq<mk_hlist!(U1, U3)>>::Output, B0>();
+    assert_type_eq::<<mk_hlist!() as SetEq<mk_hlist!()>>::Output, B1>();
+    assert_type_eq::<<mk_hlist!() as MultisetEq<B>>::Output, B0>();
+}
+
+#[test]
+fn deduplicate() {
+    assert_type_eq::<
+        <mk_hlist!(U1, U1, U2, U2, U2, U3) as Deduplicate>::Output,
+        mk_hlist!(U1, U2, U3),
+    >();
+    assert_type_eq::<<mk_hlist!(U1, U2) as Deduplicate>::Output, mk_hlist!(U1, U2)>();
+    assert_type_eq::<<mk_hlist!() as Deduplicate>::Output, mk_hlist!()>();
+}
+
+#[test]
+fn complement() {
+    type Lines = mk_hlist!(U0, U1, U2, U3, U4, U5, U6, U7);
+    assert_type_eq::<<mk_hlist!() as Complement<Lines>>::Output, Lines>();
+    assert_type_eq::<<Lines as Complement<Lines>>::Output, mk_hlist!()>();
+    assert_type_eq::<
+        <mk_hlist!(U1, U2, U5, U7) as Complement<Lines>>::Output,
+        mk_hlist!(U0, U3, U4, U6),
+    >();
+}
+
+struct AddCounts;
+
+impl<K, A: core::ops::Add<B>, B> TypeBinaryFn<KV<K, A>, KV<K, B>> for AddCounts {
+    type Output = KV<K, typenum::Sum<A, B>>;
+}
+
+struct Left;
+
+impl<A, B> TypeBinaryFn<A, B> for Left {
+    type Output = A;
+}
+
+#[test]
+fn intersect_with_merges_equal_fields() {
+    type A = mk_hlist!(KV<U1, U2>, KV<U3, U1>, KV<U4, U6>);
+    type B = mk_hlist!(KV<U0, U9>, KV<U3, U4>, KV<U4, U1>);
+    type Both = <A as IntersectWith<B, AddCounts>>::Output;
+    assert_type_eq::<Both, mk_hlist!(KV<U3, U5>, KV<U4, U7>)>();
+    sorted::<Both>();
+    assert_type_eq::<<A as IntersectWith<mk_hlist!(), AddCounts>>::Output, mk_hlist!()>();
+}
+
+#[test]
+fn intersect_with_left_is_intersect() {
+    type A = mk_hlist!(U1, U3, U5, U7);
+    type B = mk_hlist!(U3, U4, U7);
+    assert_type_eq::<<A as IntersectWith<B, Left>>::Output, <A as Intersect<B>>::Output>();
+}
@@ -0,0 +1,101 @@
+use sorted_hlist::prelude::*;
+use sorted_hlist::{
+    Insert, InsertUnique, MergeSorted, MergeSortedUnchecked, Normalize, Remove, ReplaceElement,
+    Sort,
+};
+use typenum::{U1, U2, U3, U4, U5, U6};
+
+const fn sorted<L: SortedHList>() {}
+
+#[test]
+fn insert_positions() {
+    type L = mk_hlist!(U2, U4);
+    assert_type_eq::<<L as Insert<U1>>::Output, mk_hlist!(U1, U2, U4)>();
+    assert_type_eq::<<L as Insert<U3>>::Output, mk_hlist!(U2, U3, U4)>();
+    assert_type_eq::<<L as Insert<U5>>::Output, mk_hlist!(U2, U4, U5)>();
+    assert_type_eq::<<L as Insert<U2>>::Output, mk_hlist!(U2, U2, U4)>();
+    assert_type_eq::<<mk_hlist!() as Insert<U1>>::Output, mk_hlist!(U1)>();
+}
+
+#[test]
+fn insert_unique_positions() {
+    type L = mk_hlist!(U2, U4);
+    assert_type_eq::<<L as InsertUnique<U1>>::Output, mk_hlist!(U1, U2, U4)>();
+    assert_type_eq::<<L as InsertUnique<U3>>::Output, mk_hlist!(U2, U3, U4)>();
+    assert_type_eq::<<L as InsertUnique<U5>>::Output, mk_hlist!(U2, U4, U5)>();
+    assert_type_eq::<<mk_hlist!() as InsertUnique<U1>>::Output, mk_hlist!(U1)>();
+    sorted::<<L as InsertUnique<U3>>::Output>();
+}
+
+#[test]
+fn remove_elements() {
+    type L = mk_hlist!(U1, U2, U2, U4);
+    assert_type_eq::<<L as Remove<U1>>::Output, mk_hlist!(U2, U2, U4)>();
+    assert_type_eq::<<L as Remove<U2>>::Output, mk_hlist!(U1, U2, U4)>();
+    assert_type_eq::<<L as Remove<U4>>::Output, mk_hlist!(U1, U2, U2)>();
+}
+
+#[test]
+fn replace_elements() {
+    type L = mk_hlist!(U2, U3, U5);
+    // moves earlier, later, and stays in place
+    assert_type_eq::<<L as ReplaceElement<U5, U1>>::Output, mk_hlist!(U1, U2, U3)>();
+    assert_type_eq::<<L as ReplaceElement<U2, U4>>::Output, mk_hlist!(U3, U4, U5)>();
+    assert_type_eq::<<L as ReplaceElement<U3, U4>>::Output, mk_hlist!(U2, U4, U5)>();
+    assert_type_eq::<<L as ReplaceElement<U3, U3>>::Output, L>();
+    sorted::<<L as ReplaceElement<U2, U4>>::Output>();
+}
+
+#[test]
+fn sort_lists() {
+    assert_type_eq::<<mk_hlist!() as Sort>::Output, mk_hlist!()>();
+    assert_type_eq::<<mk_hlist!(U3, U1, U2) as Sort>::Output, mk_hlist!(U1, U2, U3)>();
+    assert_type_eq::<<mk_hlist!(U5, U4, U3, U2, U1) as Sort>::Output, mk_hlist!(U1, U2, U3, U4, U5)>(
+    );
+    assert_type_eq::<<mk_hlist!(U2, U1, U2) as Sort>::Output, mk_hlist!(U1, U2, U2)>();
+}
+
+#[test]
+fn sort_output_is_sorted() {
+    sorted::<<mk_hlist!(U4, U1, U3, U1, U5) as Sort>::Output>();
+}
+
+#[test]
+fn merge_sorted_keeps_duplicates() {
+    type A = mk_hlist!(U1, U3, U3, U5);
+    type B = mk_hlist!(U2, U3, U6);
+    type Merged = <A as MergeSorted<B>>::Output;
+    assert_type_eq::<Merged, mk_hlist!(U1, U2, U3, U3, U3, U5, U6)>();
+    sorted::<Merged>();
+
+    assert_type_eq::<<A as MergeSorted<mk_hlist!()>>::Output, A>();
+    assert_type_eq::<<mk_hlist!() as MergeSorted<B>>::Output, B>();
+    // Union keeps one of each pair of equal elements
+    assert_type_eq::<<A as Union<B>>::Output, mk_hlist!(U1, U2, U3, U3, U5, U6)>();
+}
+
+#[test]
+fn merge_sorted_output_is_sorted() {
+    fn merge<A: MergeSorted<B>, B: SortedHList>() {
+        sorted::<<A as MergeSorted<B>>::Output>();
+    }
+    merge::<mk_hlist!(U1, U5), mk_hlist!(U2, U5)>();
+}
+
+#[test]
+fn merge_sorted_unchecked_skips_the_check() {
+    type Merged = <mk_hlist!(U2) as MergeSortedUnchecked<mk_hlist!(U3, U1)>>::Output;
+    assert_type_eq::<Merged, mk_hlist!(U2, U3, U1)>();
+}
+
+#[test]
+fn normalize() {
+    type Sorted = mk_hlist!(U1, U2, U5);
+    assert_type_eq::<Normalize<Sorted>, Sorted>();
+
+    type Reversed = mk_hlist!(U5, U5, U3, U2, U2, U2, U1);
+    assert_type_eq::<Normalize<Reversed>, mk_hlist!(U1, U2, U3, U5)>();
+    sorted::<Normalize<Reversed>>();
+
+    assert_type_eq::<Normalize<mk_hlist!()>, mk_hlist!()>();
+}
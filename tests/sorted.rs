@@ -0,0 +1,76 @@
+use sorted_hlist::prelude::*;
+use sorted_hlist::{sorted, Sorted};
+use typenum::{U1, U2, U3, U4, U5, U6, U7, U8, U9};
+
+const fn type_of<L: SortedHList>(_: Sorted<L>) -> core::marker::PhantomData<L> {
+    core::marker::PhantomData
+}
+
+#[test]
+fn sorted_token_construction() {
+    let a: Sorted<mk_hlist!(U1, U2)> = Sorted::new();
+    let b = sorted::<mk_hlist!(U1, U2)>();
+    let c: Sorted<mk_hlist!(U1, U2)> = Default::default();
+    let _ = (a, b, c, a);
+    assert_eq!(core::mem::size_of::<Sorted<mk_hlist!(U1, U2)>>(), 0);
+}
+
+#[test]
+fn sorted_token_intersect() {
+    let common = sorted::<mk_hlist!(U1, U2, U3)>().intersect(sorted::<mk_hlist!(U2, U3, U4)>());
+    let _: Sorted<mk_hlist!(U2, U3)> = common;
+}
+
+#[test]
+fn sorted_token_union_and_difference() {
+    let a = sorted::<mk_hlist!(U1, U2, U3)>();
+    let b = sorted::<mk_hlist!(U2, U3, U4)>();
+    type All = mk_hlist!(U1, U2, U3, U4);
+    let _: Sorted<All> = a.union(b);
+    let _: Sorted<mk_hlist!(U1)> = a.difference(b);
+    let _: Sorted<mk_hlist!(U4)> = b.difference(a);
+    let _: Sorted<mk_hlist!(U1, U4)> = a.union(b).difference(a.intersect(b));
+}
+
+#[test]
+fn sorted_token_chain() {
+    let a = sorted::<mk_hlist!(U1, U2, U3, U4, U5, U6, U7, U8, U9)>();
+    let b = sorted::<mk_hlist!(U2, U3, U4, U5, U6, U7, U8)>();
+    let c = sorted::<mk_hlist!(U1, U3, U5, U7, U9)>();
+    let d = sorted::<mk_hlist!(U3, U4, U5, U6)>();
+    let result = a.intersect(b).intersect(c).intersect(d);
+
+    fn check<L: TypeEq<mk_hlist!(U3, U5)>>(_: core::marker::PhantomData<L>) {}
+    check(type_of(result));
+}
+
+/// Builds `mk_hlist!(U1, ..., U64)`.
+macro_rules! one_to_sixty_four {
+    ($($n:ident)*) => { mk_hlist!($(typenum::$n),*) };
+    () => {
+        one_to_sixty_four!(
+            U1 U2 U3 U4 U5 U6 U7 U8 U9 U10 U11 U12 U13 U14 U15 U16
+            U17 U18 U19 U20 U21 U22 U23 U24 U25 U26 U27 U28 U29 U30 U31 U32
+            U33 U34 U35 U36 U37 U38 U39 U40 U41 U42 U43 U44 U45 U46 U47 U48
+            U49 U50 U51 U52 U53 U54 U55 U56 U57 U58 U59 U60 U61 U62 U63 U64
+        )
+    };
+}
+
+const fn sorted_bound<L: SortedHList>() {}
+
+#[test]
+fn sorted_lists_of_every_parity() {
+    sorted_bound::<mk_hlist!()>();
+    sorted_bound::<mk_hlist!(U1)>();
+    sorted_bound::<mk_hlist!(U1, U1)>();
+    sorted_bound::<mk_hlist!(U1, U2, U2)>();
+    sorted_bound::<mk_hlist!(U1, U2, U3, U4)>();
+    sorted_bound::<mk_hlist!(U1, U2, U3, U4, U5)>();
+}
+
+#[test]
+fn sixty_four_elements_at_default_recursion_limit() {
+    sorted_bound::<one_to_sixty_four!()>();
+    let _ = sorted::<one_to_sixty_four!()>();
+}
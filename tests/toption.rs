@@ -0,0 +1,32 @@
+use sorted_hlist::prelude::*;
+use sorted_hlist::{Find, IsEven, IsNone, IsSome, Succ, TNone, TOptionMap, TSome, UnwrapOr};
+use typenum::{B0, B1, U0, U1, U2, U3, U4, U5, U6};
+
+#[test]
+fn map_both_variants() {
+    assert_type_eq::<<TSome<U2> as TOptionMap<Succ>>::Output, TSome<U3>>();
+    assert_type_eq::<<TNone as TOptionMap<Succ>>::Output, TNone>();
+}
+
+#[test]
+fn unwrap_or_both_variants() {
+    assert_type_eq::<<TSome<u8> as UnwrapOr<u16>>::Output, u8>();
+    assert_type_eq::<<TNone as UnwrapOr<u16>>::Output, u16>();
+}
+
+#[test]
+fn is_some_and_is_none() {
+    assert_type_eq::<<TSome<u8> as IsSome>::Output, B1>();
+    assert_type_eq::<<TNone as IsSome>::Output, B0>();
+    assert_type_eq::<<TSome<u8> as IsNone>::Output, B0>();
+    assert_type_eq::<<TNone as IsNone>::Output, B1>();
+}
+
+type FirstEvenPlusOne<L> =
+    <<<L as Find<IsEven>>::Output as TOptionMap<Succ>>::Output as UnwrapOr<U0>>::Output;
+
+#[test]
+fn find_map_unwrap_or() {
+    assert_type_eq::<FirstEvenPlusOne<mk_hlist!(U1, U4, U6)>, U5>();
+    assert_type_eq::<FirstEvenPlusOne<mk_hlist!(U1, U3)>, U0>();
+}
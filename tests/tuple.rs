@@ -0,0 +1,38 @@
+use sorted_hlist::prelude::*;
+use sorted_hlist::{FromTuple, IntoTuple, Sort};
+use typenum::{U1, U2, U3, U4, U5};
+
+type Round<T> = <<T as FromTuple>::Output as IntoTuple>::Output;
+
+#[test]
+fn tuple_to_hlist() {
+    assert_type_eq::<<() as FromTuple>::Output, mk_hlist!()>();
+    assert_type_eq::<<(u8,) as FromTuple>::Output, mk_hlist!(u8)>();
+    assert_type_eq::<<(u8, bool, char) as FromTuple>::Output, mk_hlist!(u8, bool, char)>();
+}
+
+#[test]
+fn hlist_to_tuple() {
+    assert_type_eq::<<mk_hlist!() as IntoTuple>::Output, ()>();
+    assert_type_eq::<<mk_hlist!(u8) as IntoTuple>::Output, (u8,)>();
+    assert_type_eq::<<mk_hlist!(U1, U2) as IntoTuple>::Output, (U1, U2)>();
+}
+
+#[test]
+fn round_trips() {
+    assert_type_eq::<Round<()>, ()>();
+    assert_type_eq::<Round<(u8,)>, (u8,)>();
+    assert_type_eq::<Round<(u8, u16, u32, u64)>, (u8, u16, u32, u64)>();
+    type Twelve = (u8, u16, u32, u64, i8, i16, i32, i64, bool, char, f32, f64);
+    assert_type_eq::<Round<Twelve>, Twelve>();
+}
+
+#[test]
+fn tuple_pipeline() {
+    type Supported = (U4, U1, U3, U2);
+    type Wanted = (U5, U2, U4);
+    type Common = <<<Supported as FromTuple>::Output as Sort>::Output as Intersect<
+        <<Wanted as FromTuple>::Output as Sort>::Output,
+    >>::Output;
+    assert_type_eq::<<Common as IntoTuple>::Output, (U2, U4)>();
+}
@@ -0,0 +1,53 @@
+#![cfg(feature = "std")]
+
+use core::any::TypeId;
+use sorted_hlist::prelude::*;
+use sorted_hlist::AsSortedTypeIds;
+use typenum::{U1, U2, U3, U4, U9};
+
+#[test]
+fn type_ids_in_order() {
+    type L = mk_hlist!(U1, U4, U9);
+    let ids = <L as AsSortedTypeIds>::type_ids();
+    assert_eq!(ids.len(), 3);
+    assert_eq!(
+        ids,
+        [TypeId::of::<U1>(), TypeId::of::<U4>(), TypeId::of::<U9>()]
+    );
+    assert!(<mk_hlist!() as AsSortedTypeIds>::type_ids().is_empty());
+}
+
+#[test]
+fn type_ids_are_cached_per_list() {
+    type A = mk_hlist!(U1, U2);
+    type B = mk_hlist!(U2, U3);
+    let a = <A as AsSortedTypeIds>::type_ids();
+    assert!(core::ptr::eq(a, <A as AsSortedTypeIds>::type_ids()));
+    assert_eq!(<B as AsSortedTypeIds>::type_ids()[0], a[1]);
+}
+
+#[test]
+fn type_ids_shared_across_threads() {
+    type L = mk_hlist!(U1, U3, U9);
+    let first = <L as AsSortedTypeIds>::type_ids();
+    let others: Vec<usize> = (0..4)
+        .map(|_| std::thread::spawn(|| <L as AsSortedTypeIds>::type_ids().as_ptr() as usize))
+        .collect::<Vec<_>>()
+        .into_iter()
+        .map(|handle| handle.join().unwrap())
+        .collect();
+    assert!(others.iter().all(|&ptr| ptr == first.as_ptr() as usize));
+}
+
+#[test]
+fn type_id_set() {
+    type L = mk_hlist!(U1, U2, U2, U9);
+    let set = <L as AsSortedTypeIds>::type_id_set();
+    assert_eq!(set.len(), 3);
+    assert!(set
+        .iter()
+        .eq(<mk_hlist!(U1, U2, U9) as AsSortedTypeIds>::type_id_set().iter()));
+    assert!(set.contains(&TypeId::of::<U9>()));
+    assert!(!set.contains(&TypeId::of::<U3>()));
+    assert!(<mk_hlist!() as AsSortedTypeIds>::type_id_set().is_empty());
+}
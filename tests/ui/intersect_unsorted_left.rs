@@ -0,0 +1,10 @@
+//! `Intersect` requires the left side to be sorted.
+
+use sorted_hlist::{mk_hlist, Intersect, SortedHList};
+use typenum::{U1, U2};
+
+fn common<A: Intersect<B>, B: SortedHList>() {}
+
+fn main() {
+    common::<mk_hlist!(U2, U1), mk_hlist!(U1, U2)>();
+}
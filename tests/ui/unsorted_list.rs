@@ -0,0 +1,10 @@
+//! An unsorted list does not satisfy a `SortedHList` bound.
+
+use sorted_hlist::{mk_hlist, SortedHList};
+use typenum::{U1, U2};
+
+fn sorted_list<L: SortedHList>() {}
+
+fn main() {
+    sorted_list::<mk_hlist!(U2, U1)>();
+}
@@ -0,0 +1,11 @@
+//! An out-of-order argument to a sorted constructor is rejected.
+//!
+//! The crate has no `mk_sorted_hlist!` macro; the `sorted()` proof-token
+//! constructor stands in for it.
+
+use sorted_hlist::{mk_hlist, sorted};
+use typenum::{U1, U2, U3};
+
+fn main() {
+    let _ = sorted::<mk_hlist!(U1, U3, U2)>();
+}
@@ -0,0 +1,59 @@
+use sorted_hlist::prelude::*;
+use std::collections::HashSet;
+use typenum::{U1, U2};
+
+type L = mk_hlist!(U1, U2);
+
+/// A user struct carrying an HList as a zero-sized type tag.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+struct Tagged {
+    id: u32,
+    caps: L,
+}
+
+/// An element type that implements none of the standard traits.
+struct Opaque;
+
+#[test]
+fn construct_values() {
+    let a: L = HCons::new();
+    let b: L = Default::default();
+    let nil: HNil = Default::default();
+    assert_eq!(a, b);
+    assert_eq!(nil, HNil);
+    assert_eq!(core::mem::size_of::<L>(), 0);
+}
+
+#[test]
+fn clone_and_compare_without_element_bounds() {
+    let a: mk_hlist!(Opaque, *const u8) = HCons::new();
+    #[allow(clippy::clone_on_copy)]
+    let b = a.clone();
+    let c = a;
+    assert!(a == b && b == c);
+}
+
+#[test]
+fn debug_and_hash() {
+    assert_eq!(format!("{:?}", HNil), "HNil");
+    assert_eq!(format!("{:?}", <mk_hlist!(Opaque)>::new()), "HCons<_, _>");
+
+    let mut set = HashSet::new();
+    set.insert(<L>::new());
+    set.insert(<L>::new());
+    assert_eq!(set.len(), 1);
+}
+
+#[test]
+fn embed_in_user_struct() {
+    const TAG: L = HCons::new();
+    let t = Tagged { id: 7, caps: TAG };
+    assert_eq!(
+        t,
+        Tagged {
+            id: 7,
+            ..Default::default()
+        }
+    );
+    assert_eq!(format!("{:?}", t), "Tagged { id: 7, caps: HCons<_, _> }");
+}
@@ -0,0 +1,68 @@
+use core::marker::PhantomData;
+use sorted_hlist::prelude::*;
+use sorted_hlist::Sort;
+use typenum::{U1, U2, U3, U4, U5};
+
+fn expect<Expected, L: TypeEq<Expected>>(_: PhantomData<L>) {}
+
+fn pass_on<L: SortedHList>(witness: PhantomData<L>) -> PhantomData<L> {
+    witness
+}
+
+fn intersect_with<A, B>(
+    _: PhantomData<A>,
+    _: PhantomData<B>,
+) -> PhantomData<<A as Intersect<B>>::Output>
+where
+    A: Intersect<B>,
+    B: SortedHList,
+{
+    PhantomData
+}
+
+#[test]
+fn sorted_witness() {
+    let w = is_sorted_witness::<mk_hlist!(U1, U2, U2)>();
+    expect::<mk_hlist!(U1, U2, U2), _>(pass_on(w));
+}
+
+#[test]
+fn intersect_witness() {
+    let w = intersect::<mk_hlist!(U1, U2, U3), mk_hlist!(U2, U3, U4)>();
+    expect::<mk_hlist!(U2, U3), _>(w);
+}
+
+#[test]
+fn union_and_difference_witnesses() {
+    type A = mk_hlist!(U1, U2, U3);
+    type B = mk_hlist!(U2, U3, U4);
+    expect::<mk_hlist!(U1, U2, U3, U4), _>(union::<A, B>());
+    expect::<mk_hlist!(U1), _>(difference::<A, B>());
+    expect::<mk_hlist!(U4), _>(difference::<B, A>());
+    expect::<B, _>(pass_on(union::<mk_hlist!(), B>()));
+}
+
+#[test]
+fn witnesses_flow_through_generic_functions() {
+    let a = is_sorted_witness::<mk_hlist!(U1, U2, U3, U4)>();
+    let b = is_sorted_witness::<mk_hlist!(U2, U4, U5)>();
+    let common = pass_on(intersect_with(a, b));
+    let narrowed = intersect_with(common, is_sorted_witness::<mk_hlist!(U4)>());
+    expect::<mk_hlist!(U4), _>(pass_on(narrowed));
+}
+
+const _: () = assert_sorted::<mk_hlist!(U1, U2, U2, U3)>();
+const _: AssertSorted<mk_hlist!(U1, U3)> = AssertSorted::new();
+
+#[test]
+fn assert_sorted_in_function_body() {
+    assert_sorted::<mk_hlist!()>();
+    let _ = AssertSorted::<<mk_hlist!(U3, U1) as Sort>::Output>::new();
+}
+
+#[test]
+#[deny(unused_must_use)]
+fn assertions_by_call() {
+    AssertSorted::<mk_hlist!(U1, U2)>::new();
+    AssertLen::<mk_hlist!(U1, U2), U2>::new();
+}